@@ -50,21 +50,17 @@ define_procedure!(basic, |memgraph: &Memgraph| -> Result<()> {
     // This procedure just forwards the input parameters as procedure results.
     let result = memgraph.result_record()?;
     let args = memgraph.args()?;
-    let input_string = args.value_at(0)?;
-    let input_int = args.value_at(1)?;
-    result.insert_mgp_value(
-        c_str!("output_string"),
-        &input_string.to_mgp_value(&memgraph)?,
-    )?;
-    result.insert_mgp_value(c_str!("output_int"), &input_int.to_mgp_value(&memgraph)?)?;
+    result.insert_string(c_str!("output_string"), &args.string_at(0)?)?;
+    result.insert_int(c_str!("output_int"), args.int_at(1)?)?;
     Ok(())
 });
 
 define_procedure!(test_procedure, |memgraph: &Memgraph| -> Result<()> {
     for mgp_vertex in memgraph.vertices_iter()? {
+        let mgp_vertex = mgp_vertex?;
         let result = memgraph.result_record()?;
 
-        let mut properties: Vec<Property> = mgp_vertex.properties()?.collect();
+        let mut properties: Vec<Property> = mgp_vertex.properties()?.collect::<Result<_>>()?;
         properties.sort_by(|a, b| {
             let a_name = a.name.to_str().unwrap();
             let b_name = b.name.to_str().unwrap();
@@ -114,7 +110,7 @@ define_procedure!(test_procedure, |memgraph: &Memgraph| -> Result<()> {
 
         match mgp_vertex.out_edges()?.next() {
             Some(edge) => {
-                let edge_type = edge.edge_type()?;
+                let edge_type = edge?.edge_type()?;
                 result.insert_string(c_str!("first_edge_type"), &edge_type)?;
             }
             None => {
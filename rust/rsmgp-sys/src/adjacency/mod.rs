@@ -0,0 +1,152 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Adjacency of the whole graph, built with a single pass over the vertices so that graph
+//! algorithms don't have to traverse the graph through the C API on their own.
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+
+use crate::edge::*;
+use crate::id::*;
+use crate::memgraph::*;
+use crate::result::*;
+use crate::value::*;
+
+/// Defines which edges of a vertex are followed to its neighbors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Outgoing,
+    Incoming,
+    Both,
+}
+
+/// Filters applied while building an [AdjacencyView].
+///
+/// * `direction` - Edges followed from each vertex.
+/// * `edge_types` - Only edges of the given types are included, all edges if empty.
+/// * `weight_property` - Numeric edge property read as the edge weight. Edges without the
+///   property (or all edges if `None`) get no weight.
+/// * `ignore_self_loops` - Leaves out edges from a vertex to itself. Otherwise the vertex is its
+///   own neighbor, twice if `direction` is [Direction::Both].
+pub struct AdjacencyConfig<'a> {
+    pub direction: Direction,
+    pub edge_types: &'a [&'a CStr],
+    pub weight_property: Option<&'a CStr>,
+    pub ignore_self_loops: bool,
+}
+
+impl Default for AdjacencyConfig<'_> {
+    fn default() -> Self {
+        AdjacencyConfig {
+            direction: Direction::Outgoing,
+            edge_types: &[],
+            weight_property: None,
+            ignore_self_loops: true,
+        }
+    }
+}
+
+/// Neighbors of each vertex together with the optional weight of the connecting edge.
+///
+/// Every vertex of the graph has an entry, vertices without (matching) edges have no neighbors.
+/// Parallel edges appear as multiple entries of the same neighbor. The view is a snapshot, so it
+/// can be stored in a [crate::cache::PrecomputedCache] and reused between procedure calls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdjacencyView {
+    neighbors: HashMap<Id, Vec<(Id, Option<f64>)>>,
+}
+
+impl AdjacencyView {
+    /// Builds the view of the whole graph, the vertices are iterated once.
+    ///
+    /// Checks [Memgraph::must_abort] once per vertex and returns [Error::ProcedureAborted] if the
+    /// query was terminated. Fails with [Error::NonNumericEdgeWeight] if the weight property of
+    /// an included edge isn't a number.
+    pub fn build(memgraph: &Memgraph, config: &AdjacencyConfig) -> Result<AdjacencyView> {
+        let mut neighbors = HashMap::new();
+        for vertex in memgraph.vertices_iter()? {
+            memgraph.check_abort()?;
+            let vertex = vertex?;
+            let id = vertex.id();
+            let mut vertex_neighbors = Vec::new();
+            let mut add_neighbor = |neighbor: Id, edge: &Edge| -> Result<()> {
+                if config.ignore_self_loops && neighbor == id {
+                    return Ok(());
+                }
+                if let Some(weight) = edge_weight(edge, config)? {
+                    vertex_neighbors.push((neighbor, weight));
+                }
+                Ok(())
+            };
+            if config.direction != Direction::Incoming {
+                for edge in vertex.out_edges()? {
+                    let edge = edge?;
+                    add_neighbor(edge.to_vertex()?.id(), &edge)?;
+                }
+            }
+            if config.direction != Direction::Outgoing {
+                for edge in vertex.in_edges()? {
+                    let edge = edge?;
+                    add_neighbor(edge.from_vertex()?.id(), &edge)?;
+                }
+            }
+            neighbors.insert(id, vertex_neighbors);
+        }
+        Ok(AdjacencyView { neighbors })
+    }
+
+    /// Returns the neighbors of the given vertex, empty if the vertex isn't part of the view.
+    pub fn neighbors(&self, id: impl Into<Id>) -> &[(Id, Option<f64>)] {
+        self.neighbors
+            .get(&id.into())
+            .map(|neighbors| neighbors.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.neighbors.len()
+    }
+
+    /// Returns the ids of all vertices in arbitrary order.
+    pub fn vertex_ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.neighbors.keys().copied()
+    }
+
+    pub fn into_hash_map(self) -> HashMap<Id, Vec<(Id, Option<f64>)>> {
+        self.neighbors
+    }
+}
+
+/// Returns `None` if the edge is filtered out, otherwise the optional edge weight.
+fn edge_weight(edge: &Edge, config: &AdjacencyConfig) -> Result<Option<Option<f64>>> {
+    if !config.edge_types.is_empty() {
+        let edge_type = edge.edge_type()?;
+        if !config.edge_types.contains(&edge_type.as_c_str()) {
+            return Ok(None);
+        }
+    }
+    let weight_property = match config.weight_property {
+        Some(weight_property) => weight_property,
+        None => return Ok(Some(None)),
+    };
+    match edge.property(weight_property)? {
+        None => Ok(Some(None)),
+        Some(Value::Int(weight)) => Ok(Some(Some(weight as f64))),
+        Some(Value::Float(weight)) => Ok(Some(Some(weight))),
+        Some(_) => Err(Error::NonNumericEdgeWeight),
+    }
+}
+
+#[cfg(test)]
+mod tests;
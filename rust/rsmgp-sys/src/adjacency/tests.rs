@@ -0,0 +1,149 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use c_str_macro::c_str;
+use serial_test::serial;
+
+use super::*;
+use crate::mgp::mock_ffi::*;
+use crate::mgp::*;
+use crate::testing::alloc::assert_no_leaks;
+use crate::testing::fake_graph::*;
+use crate::{mock_fake_graph, with_dummy};
+
+#[test]
+#[serial]
+fn test_build_outgoing_weighted() {
+    mock_fake_graph!(FAKE_EDGES);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let config = AdjacencyConfig {
+            weight_property: Some(c_str!("weight")),
+            ..Default::default()
+        };
+        let view = AdjacencyView::build(&memgraph, &config).unwrap();
+        assert_eq!(view.vertex_count(), 3);
+        assert_eq!(view.neighbors(1), &[(Id(2), Some(0.5)), (Id(3), Some(2.0))]);
+        assert_eq!(view.neighbors(2), &[(Id(3), None)]);
+        assert!(view.neighbors(3).is_empty());
+        assert!(view.neighbors(4).is_empty());
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_build_incoming_unweighted() {
+    mock_fake_graph!(FAKE_EDGES);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let config = AdjacencyConfig {
+            direction: Direction::Incoming,
+            ..Default::default()
+        };
+        let view = AdjacencyView::build(&memgraph, &config).unwrap();
+        assert!(view.neighbors(1).is_empty());
+        assert_eq!(view.neighbors(2), &[(Id(1), None)]);
+        assert_eq!(view.neighbors(3), &[(Id(1), None), (Id(2), None)]);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_build_both_directions_filtered_by_type() {
+    mock_fake_graph!(FAKE_EDGES);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let config = AdjacencyConfig {
+            direction: Direction::Both,
+            edge_types: &[c_str!("LIKES")],
+            weight_property: None,
+            ignore_self_loops: true,
+        };
+        let view = AdjacencyView::build(&memgraph, &config).unwrap();
+        assert!(view.neighbors(1).is_empty());
+        assert_eq!(view.neighbors(2), &[(Id(3), None)]);
+        assert_eq!(view.neighbors(3), &[(Id(2), None)]);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_build_ignore_self_loops() {
+    mock_fake_graph!(FAKE_EDGES_SELF_LOOP);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let config = AdjacencyConfig {
+            direction: Direction::Both,
+            ..Default::default()
+        };
+        let view = AdjacencyView::build(&memgraph, &config).unwrap();
+        assert_eq!(view.neighbors(1), &[(Id(2), None)]);
+        assert_eq!(view.neighbors(2), &[(Id(1), None)]);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_build_keep_self_loops() {
+    mock_fake_graph!(FAKE_EDGES_SELF_LOOP);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let config = AdjacencyConfig {
+            direction: Direction::Both,
+            ignore_self_loops: false,
+            ..Default::default()
+        };
+        let view = AdjacencyView::build(&memgraph, &config).unwrap();
+        assert_eq!(
+            view.neighbors(1),
+            &[(Id(1), None), (Id(2), None), (Id(1), None)]
+        );
+        assert_eq!(view.neighbors(2), &[(Id(1), None)]);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_build_non_numeric_weight() {
+    mock_fake_graph!(FAKE_EDGES_BOOL_WEIGHT);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let config = AdjacencyConfig {
+            weight_property: Some(c_str!("weight")),
+            ..Default::default()
+        };
+        assert_eq!(
+            AdjacencyView::build(&memgraph, &config).err().unwrap(),
+            Error::NonNumericEdgeWeight
+        );
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_build_error_mid_stream() {
+    mock_fake_graph!(FAKE_EDGES, Some(1));
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let view = AdjacencyView::build(&memgraph, &AdjacencyConfig::default());
+        assert_eq!(view.err().unwrap(), Error::UnableToReturnNextEdge);
+    });
+    assert_no_leaks();
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Caching of expensive precomputations (e.g., adjacency of the whole graph) between procedure
+//! calls.
+
+use std::sync::{Arc, Mutex};
+
+use crate::result::*;
+
+/// Holds a single precomputed structure together with the token of the graph it was built for.
+///
+/// The C API doesn't expose a graph version, so the token has to be provided by the caller, e.g.,
+/// a counter maintained by a trigger or the vertex and edge counts. The cached structure is reused
+/// as long as the token is the same and rebuilt once it changes.
+///
+/// A token which doesn't change on every graph modification (e.g. the counts after an edge was
+/// replaced by another one) makes the procedure run on stale data. If that's a concern, let the
+/// users opt out with a procedure argument passed to [PrecomputedCache::get_or_build_if].
+///
+/// Intended to be used as a `static` inside the query module:
+///
+/// ```
+/// use rsmgp_sys::cache::PrecomputedCache;
+///
+/// static ADJACENCY: PrecomputedCache<u64, Vec<Vec<i64>>> = PrecomputedCache::new();
+/// ```
+pub struct PrecomputedCache<K, T> {
+    entry: Mutex<Option<(K, Arc<T>)>>,
+}
+
+impl<K: PartialEq, T> PrecomputedCache<K, T> {
+    pub const fn new() -> PrecomputedCache<K, T> {
+        PrecomputedCache {
+            entry: Mutex::new(None),
+        }
+    }
+
+    /// Returns the structure cached for the given token or builds it with `build`.
+    ///
+    /// `build` should periodically check [crate::memgraph::Memgraph::must_abort] and return
+    /// [Error::ProcedureAborted] if the query was terminated. A failed build leaves the cache
+    /// empty, so the next call builds the structure from scratch.
+    pub fn get_or_build<F>(&self, token: K, build: F) -> Result<Arc<T>>
+    where
+        F: FnOnce() -> Result<T>,
+    {
+        let mut entry = self.entry.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((cached_token, value)) = entry.as_ref() {
+            if *cached_token == token {
+                return Ok(value.clone());
+            }
+        }
+        *entry = None;
+        let value = Arc::new(build()?);
+        *entry = Some((token, value.clone()));
+        Ok(value)
+    }
+
+    /// Same as [PrecomputedCache::get_or_build] if `use_cache` is `true`. Otherwise a fresh
+    /// structure is built only for the caller, the cached one stays for the other callers.
+    pub fn get_or_build_if<F>(&self, use_cache: bool, token: K, build: F) -> Result<Arc<T>>
+    where
+        F: FnOnce() -> Result<T>,
+    {
+        if use_cache {
+            return self.get_or_build(token, build);
+        }
+        Ok(Arc::new(build()?))
+    }
+
+    /// Drops the cached structure.
+    pub fn invalidate(&self) {
+        *self.entry.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+impl<K: PartialEq, T> Default for PrecomputedCache<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serial_test::serial;
+
+use super::*;
+use crate::memgraph::Memgraph;
+use crate::mgp::mock_ffi::*;
+use crate::with_dummy;
+
+#[test]
+fn test_cache_hit() {
+    let cache = PrecomputedCache::new();
+    let mut builds = 0;
+    for _ in 0..3 {
+        let value = cache
+            .get_or_build(1, || {
+                builds += 1;
+                Ok(vec![1, 2, 3])
+            })
+            .unwrap();
+        assert_eq!(*value, vec![1, 2, 3]);
+    }
+    assert_eq!(builds, 1);
+}
+
+#[test]
+fn test_invalidation_on_graph_change() {
+    let cache = PrecomputedCache::new();
+    assert_eq!(*cache.get_or_build(1, || Ok(10)).unwrap(), 10);
+    assert_eq!(*cache.get_or_build(2, || Ok(20)).unwrap(), 20);
+    assert_eq!(*cache.get_or_build(2, || Ok(30)).unwrap(), 20);
+    cache.invalidate();
+    assert_eq!(*cache.get_or_build(2, || Ok(30)).unwrap(), 30);
+}
+
+#[test]
+fn test_rebuild_when_cache_disabled() {
+    let cache = PrecomputedCache::new();
+    assert_eq!(*cache.get_or_build_if(true, 1, || Ok(10)).unwrap(), 10);
+    assert_eq!(*cache.get_or_build_if(true, 1, || Ok(20)).unwrap(), 10);
+    // The same token, but the caller doesn't trust the cached structure.
+    assert_eq!(*cache.get_or_build_if(false, 1, || Ok(30)).unwrap(), 30);
+    // The uncached build neither replaces nor drops the cached structure.
+    assert_eq!(*cache.get_or_build_if(true, 1, || Ok(40)).unwrap(), 10);
+}
+
+#[test]
+#[serial]
+fn test_cancellation_mid_build() {
+    let mut must_abort_calls = 0;
+    let ctx_must_abort = mgp_must_abort_context();
+    ctx_must_abort.expect().times(3).returning(move |_| {
+        must_abort_calls += 1;
+        if must_abort_calls == 3 {
+            1
+        } else {
+            0
+        }
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let cache = PrecomputedCache::new();
+        let result = cache.get_or_build(1, || {
+            let mut adjacency = Vec::new();
+            for vertex in 0..10 {
+                if memgraph.must_abort() {
+                    return Err(Error::ProcedureAborted);
+                }
+                adjacency.push(vertex);
+            }
+            Ok(adjacency)
+        });
+        assert_eq!(result.err().unwrap(), Error::ProcedureAborted);
+        // Nothing is cached after the cancelled build.
+        assert!(cache.get_or_build(1, || Ok(Vec::new())).unwrap().is_empty());
+    });
+}
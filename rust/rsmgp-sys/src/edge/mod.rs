@@ -15,12 +15,14 @@
 
 use std::ffi::{CStr, CString};
 
+use crate::adjacency::Direction;
+use crate::id::*;
 use crate::memgraph::*;
 use crate::mgp::*;
 use crate::property::*;
 use crate::result::*;
 use crate::value::*;
-use crate::vertex::Vertex;
+use crate::vertex::{Vertex, VerticesIterator};
 // Required here, if not present tests linking fails.
 #[double]
 use crate::mgp::ffi;
@@ -59,30 +61,68 @@ impl Drop for EdgesIterator {
 }
 
 impl Iterator for EdgesIterator {
-    type Item = Edge;
+    type Item = Result<Edge>;
 
-    fn next(&mut self) -> Option<Edge> {
+    /// Yields an error if the next edge can't be read or copied.
+    fn next(&mut self) -> Option<Result<Edge>> {
         unsafe {
             let data = if self.is_first {
                 self.is_first = false;
-                invoke_mgp_func!(*mut mgp_edge, ffi::mgp_edges_iterator_get, self.ptr).unwrap()
+                invoke_mgp_func!(*mut mgp_edge, ffi::mgp_edges_iterator_get, self.ptr)
             } else {
                 invoke_mgp_func!(*mut mgp_edge, ffi::mgp_edges_iterator_next, self.ptr)
-                    .expect("Unable to get next edge during edges iteration.")
             };
 
-            if data.is_null() {
-                None
-            } else {
-                Some(match Edge::mgp_copy(data, &self.memgraph) {
-                    Ok(v) => v,
-                    Err(_) => panic!("Unable to create edge during edges iteration."),
-                })
+            match data {
+                Err(_) => Some(Err(Error::UnableToReturnNextEdge)),
+                Ok(data) if data.is_null() => None,
+                Ok(data) => Some(Edge::mgp_copy(data, &self.memgraph)),
             }
         }
     }
 }
 
+/// Iterator over all graph edges, take a look at [Memgraph::edges_iter].
+pub struct GraphEdgesIterator {
+    // Declared before the source vertex so that the edges iterator is destroyed first.
+    edges: Option<EdgesIterator>,
+    vertex: Option<Vertex>,
+    vertices: VerticesIterator,
+}
+
+impl GraphEdgesIterator {
+    pub(crate) fn new(vertices: VerticesIterator) -> GraphEdgesIterator {
+        GraphEdgesIterator {
+            edges: None,
+            vertex: None,
+            vertices,
+        }
+    }
+}
+
+impl Iterator for GraphEdgesIterator {
+    type Item = Result<Edge>;
+
+    /// Yields an error if the next vertex or its outgoing edges can't be read.
+    fn next(&mut self) -> Option<Result<Edge>> {
+        loop {
+            if let Some(edge) = self.edges.as_mut().and_then(|edges| edges.next()) {
+                return Some(edge);
+            }
+            self.edges = None;
+            let vertex = match self.vertices.next()? {
+                Ok(vertex) => vertex,
+                Err(e) => return Some(Err(e)),
+            };
+            match vertex.out_edges() {
+                Ok(edges) => self.edges = Some(edges),
+                Err(e) => return Some(Err(e)),
+            }
+            self.vertex = Some(vertex);
+        }
+    }
+}
+
 pub struct Edge {
     ptr: *mut mgp_edge,
     memgraph: Memgraph,
@@ -130,6 +170,16 @@ impl Edge {
         Ok(Edge::new(mgp_copy, &memgraph))
     }
 
+    /// Returns the underlying [mgp_edge] pointer for interop with other C libraries that the safe
+    /// API doesn't cover. The ownership stays with the edge.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is valid only while the edge is alive and must not be destroyed by the caller.
+    pub unsafe fn as_ptr(&self) -> *mut mgp_edge {
+        self.ptr
+    }
+
     /// Returns the underlying [mgp_edge] pointer.
     pub(crate) fn mgp_ptr(&self) -> *mut mgp_edge {
         self.ptr
@@ -139,18 +189,25 @@ impl Edge {
         self.ptr = new_ptr;
     }
 
+    /// Returns an owned copy of the edge, e.g., to keep an edge yielded by an iterator after the
+    /// iteration.
     pub fn copy(&self) -> Result<Edge> {
         unsafe { Edge::mgp_copy(self.ptr, &self.memgraph) }
     }
 
-    pub fn id(&self) -> i64 {
+    /// Returns the underlying edge id.
+    pub fn id(&self) -> Id {
         unsafe {
-            invoke_mgp_func!(mgp_edge_id, ffi::mgp_edge_get_id, self.ptr)
-                .unwrap()
-                .as_int
+            Id(
+                invoke_mgp_func!(mgp_edge_id, ffi::mgp_edge_get_id, self.ptr)
+                    .unwrap()
+                    .as_int,
+            )
         }
     }
 
+    /// Returns a copy of the edge type name. Nothing is cached, each call reads the type from
+    /// Memgraph.
     pub fn edge_type(&self) -> Result<CString> {
         unsafe {
             let mgp_edge_type = invoke_mgp_func_with_res!(
@@ -163,6 +220,7 @@ impl Edge {
         }
     }
 
+    /// Returns a copy of the source (start) vertex.
     pub fn from_vertex(&self) -> Result<Vertex> {
         unsafe {
             let mgp_vertex =
@@ -171,6 +229,7 @@ impl Edge {
         }
     }
 
+    /// Returns a copy of the destination (end) vertex.
     pub fn to_vertex(&self) -> Result<Vertex> {
         unsafe {
             let mgp_vertex =
@@ -179,35 +238,52 @@ impl Edge {
         }
     }
 
-    pub fn property(&self, name: &CStr) -> Result<Property> {
+    /// Returns the direction of the edge relative to `vertex`, [Direction::Outgoing] if the edge
+    /// starts at it and [Direction::Incoming] if it ends at it. A self-loop is
+    /// [Direction::Both], an edge not connected to `vertex` is `None`.
+    pub fn direction_from(&self, vertex: &Vertex) -> Result<Option<Direction>> {
+        let outgoing = self.from_vertex()?.id() == vertex.id();
+        let incoming = self.to_vertex()?.id() == vertex.id();
+        Ok(match (outgoing, incoming) {
+            (true, true) => Some(Direction::Both),
+            (true, false) => Some(Direction::Outgoing),
+            (false, true) => Some(Direction::Incoming),
+            (false, false) => None,
+        })
+    }
+
+    /// Returns the value of the property called `name`, or `None` if the edge doesn't have it.
+    ///
+    /// The intermediate `mgp_value` is destroyed once it's converted to [Value].
+    pub fn property(&self, name: &CStr) -> Result<Option<Value>> {
+        let name_string = || name.to_string_lossy().into_owned();
         unsafe {
-            let mgp_value = invoke_mgp_func!(
+            let mgp_value = match invoke_mgp_func!(
                 *mut mgp_value,
                 ffi::mgp_edge_get_property,
                 self.ptr,
                 name.as_ptr(),
                 self.memgraph.memory_ptr()
-            );
-            match mgp_value {
-                Ok(_) => (),
-                Err(MgpError::UnableToAllocate) => {
-                    return Err(Error::UnableToReturnEdgePropertyValueAllocationError)
-                }
+            ) {
+                Ok(ptr) => MgpValue::new(ptr, &self.memgraph),
                 Err(MgpError::DeletedObject) => {
-                    return Err(Error::UnableToReturnEdgePropertyDeletedObjectError)
+                    return Err(Error::UnableToReturnEdgePropertyDeletedObjectError {
+                        name: name_string(),
+                    })
+                }
+                // Allocation is the only other documented failure.
+                Err(_) => {
+                    return Err(Error::UnableToReturnEdgePropertyValueAllocationError {
+                        name: name_string(),
+                    })
                 }
-                Err(_) => panic!("Unexpected error code is returned from MGP API"),
-            }
-            let value = match MgpValue::new(mgp_value.unwrap(), &self.memgraph).to_value() {
-                Ok(v) => v,
-                Err(_) => return Err(Error::UnableToReturnEdgePropertyValueCreationError),
             };
-            match CString::new(name.to_bytes()) {
-                Ok(c_string) => Ok(Property {
-                    name: c_string,
-                    value,
+            match mgp_value.to_value() {
+                Ok(Value::Null) => Ok(None),
+                Ok(value) => Ok(Some(value)),
+                Err(_) => Err(Error::UnableToReturnEdgePropertyValueCreationError {
+                    name: name_string(),
                 }),
-                Err(_) => Err(Error::UnableToReturnEdgePropertyNameAllocationError),
             }
         }
     }
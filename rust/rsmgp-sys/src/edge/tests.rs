@@ -1,12 +1,15 @@
 use c_str_macro::c_str;
 use serial_test::serial;
-use std::ptr::null_mut;
+use std::ptr::{null_mut, NonNull};
 
 use super::*;
 use crate::memgraph::Memgraph;
 use crate::mgp::mgp_error;
 use crate::mgp::mock_ffi::*;
-use crate::{mock_mgp_once, with_dummy};
+use crate::testing::alloc::*;
+use crate::testing::fake_graph::*;
+use crate::{mock_fake_graph, mock_mgp_once, with_dummy};
+use libc::{c_void, free};
 
 #[test]
 #[serial]
@@ -23,6 +26,38 @@ fn test_mgp_copy() {
     });
 }
 
+#[test]
+#[serial]
+fn test_copy() {
+    mock_mgp_once!(mgp_edge_copy_context, |_, _, edge_ptr_ptr| unsafe {
+        (*edge_ptr_ptr) = alloc_mgp_edge();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_edge_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    with_dummy!(Edge, |edge: &Edge| {
+        let copy = edge.copy();
+        assert!(copy.is_ok());
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_copy_unable_to_allocate() {
+    mock_mgp_once!(mgp_edge_copy_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(Edge, |edge: &Edge| {
+        let copy = edge.copy();
+        assert!(copy.is_err());
+        assert_eq!(copy.err().unwrap(), Error::UnableToCopyEdge);
+    });
+}
+
 #[test]
 #[serial]
 fn test_id() {
@@ -81,15 +116,192 @@ fn test_to_vertex() {
     });
 }
 
+#[test]
+#[serial]
+fn test_endpoint_vertex_copies() {
+    mock_mgp_once!(mgp_edge_get_from_context, |_, vertex_ptr_ptr| unsafe {
+        (*vertex_ptr_ptr) = alloc_mgp_vertex();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_edge_get_to_context, |_, vertex_ptr_ptr| unsafe {
+        (*vertex_ptr_ptr) = alloc_mgp_vertex();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_copy = mgp_vertex_copy_context();
+    ctx_copy
+        .expect()
+        .times(2)
+        .returning(|vertex_ptr, _, vertex_copy_ptr_ptr| unsafe {
+            (*vertex_copy_ptr_ptr) = vertex_ptr;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut get_id_calls = 0;
+    let ctx_id = mgp_vertex_get_id_context();
+    ctx_id
+        .expect()
+        .times(2)
+        .returning(move |_, vertex_id_ptr| unsafe {
+            get_id_calls += 1;
+            (*vertex_id_ptr).as_int = get_id_calls;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_destroy = mgp_vertex_destroy_context();
+    ctx_destroy.expect().times(2).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(Edge, |edge: &Edge| {
+        assert_eq!(edge.from_vertex().unwrap().id(), 1);
+        assert_eq!(edge.to_vertex().unwrap().id(), 2);
+    });
+}
+
+#[test]
+#[serial]
+fn test_direction_from() {
+    mock_fake_graph!(FAKE_EDGES_BOTH_WAYS);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let vertex = memgraph.vertices_iter().unwrap().next().unwrap().unwrap();
+        let neighbor = memgraph.vertices_iter().unwrap().nth(1).unwrap().unwrap();
+        let directions = |edges: EdgesIterator| {
+            edges
+                .map(|edge| {
+                    let edge = edge.unwrap();
+                    (i64::from(edge.id()), edge.direction_from(&vertex).unwrap())
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            directions(vertex.out_edges().unwrap()),
+            vec![(1, Some(Direction::Outgoing)), (3, Some(Direction::Both))]
+        );
+        assert_eq!(
+            directions(vertex.in_edges().unwrap()),
+            vec![(2, Some(Direction::Incoming)), (3, Some(Direction::Both))]
+        );
+
+        let edge = neighbor.out_edges().unwrap().next().unwrap().unwrap();
+        assert_eq!(
+            edge.direction_from(&neighbor).unwrap(),
+            Some(Direction::Outgoing)
+        );
+        assert_eq!(
+            edge.direction_from(&vertex).unwrap(),
+            Some(Direction::Incoming)
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_direction_from_unconnected() {
+    mock_fake_graph!(FAKE_EDGES);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let unconnected = memgraph.vertices_iter().unwrap().nth(2).unwrap().unwrap();
+        let edge = memgraph.edges_iter().unwrap().next().unwrap().unwrap();
+        assert_eq!(edge.direction_from(&unconnected).unwrap(), None);
+    });
+}
+
 #[test]
 #[serial]
 fn test_property() {
+    mock_mgp_once!(
+        mgp_edge_get_property_context,
+        |_, _, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_DOUBLE;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_double_context, |_, double_ptr| unsafe {
+        (*double_ptr) = 0.5;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(Edge, |edge: &Edge| {
+        match edge.property(c_str!("weight")).unwrap() {
+            Some(Value::Float(weight)) => assert_eq!(weight, 0.5),
+            _ => panic!("Expected a float weight."),
+        }
+    });
+}
+
+#[test]
+#[serial]
+fn test_missing_property() {
+    mock_mgp_once!(
+        mgp_edge_get_property_context,
+        |_, _, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_NULL;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(Edge, |edge: &Edge| {
+        assert!(edge.property(c_str!("weight")).unwrap().is_none());
+    });
+}
+
+#[test]
+#[serial]
+fn test_property_allocation_error() {
     mock_mgp_once!(mgp_edge_get_property_context, |_, _, _, _| {
         mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
     });
 
     with_dummy!(Edge, |edge: &Edge| {
-        assert!(edge.property(c_str!("prop")).is_err());
+        assert_eq!(
+            edge.property(c_str!("weight")).err().unwrap(),
+            Error::UnableToReturnEdgePropertyValueAllocationError {
+                name: "weight".to_string()
+            }
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_property_unexpected_error() {
+    let mut errors = vec![
+        mgp_error::MGP_ERROR_SERIALIZATION_ERROR,
+        mgp_error::MGP_ERROR_DELETED_OBJECT,
+    ]
+    .into_iter();
+    let ctx_get_property = mgp_edge_get_property_context();
+    ctx_get_property
+        .expect()
+        .times(2)
+        .returning(move |_, _, _, _| errors.next().unwrap());
+
+    with_dummy!(Edge, |edge: &Edge| {
+        assert_eq!(
+            edge.property(c_str!("weight")).err().unwrap(),
+            Error::UnableToReturnEdgePropertyValueAllocationError {
+                name: "weight".to_string()
+            }
+        );
+        assert_eq!(
+            edge.property(c_str!("weight")).err().unwrap(),
+            Error::UnableToReturnEdgePropertyDeletedObjectError {
+                name: "weight".to_string()
+            }
+        );
     });
 }
 
@@ -121,3 +333,46 @@ fn test_edges_iterator() {
         assert!(iterator.next().is_none());
     });
 }
+
+#[test]
+#[serial]
+fn test_edges_iterator_error() {
+    mock_mgp_once!(mgp_edges_iterator_get_context, |_, edge_ptr_ptr| unsafe {
+        (*edge_ptr_ptr) = NonNull::dangling().as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_edge_copy_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+    mock_mgp_once!(mgp_edges_iterator_next_context, |_, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut iterator = EdgesIterator::new(null_mut(), &memgraph);
+        assert_eq!(
+            iterator.next().unwrap().err().unwrap(),
+            Error::UnableToCopyEdge
+        );
+        assert_eq!(
+            iterator.next().unwrap().err().unwrap(),
+            Error::UnableToReturnNextEdge
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_as_ptr() {
+    mock_mgp_once!(mgp_edge_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    let memgraph = Memgraph::new_default();
+    unsafe {
+        let ptr = alloc_mgp_edge();
+        let edge = Edge::new(ptr, &memgraph);
+        assert_eq!(edge.as_ptr(), ptr);
+    }
+    assert_no_leaks();
+}
@@ -0,0 +1,31 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn test_round_trip() {
+    let id = Id::from(42);
+    assert_eq!(id, Id(42));
+    assert_eq!(i64::from(id), 42);
+    let raw: i64 = id.into();
+    assert_eq!(raw, 42);
+}
+
+#[test]
+fn test_compare_and_format() {
+    assert_eq!(Id(7), 7);
+    assert!(Id(1) < Id(2));
+    assert_eq!(format!("{}", Id(7)), "7");
+}
@@ -68,14 +68,20 @@ extern crate mockall_double;
 mod temporal;
 mod testing;
 
+pub mod adjacency;
+pub mod cache;
 pub mod edge;
+pub mod id;
 pub mod list;
+pub mod log;
 pub mod map;
 pub mod memgraph;
 pub mod mgp;
 pub mod path;
 pub mod property;
+pub mod ranking;
 pub mod result;
 pub mod rsmgp;
+pub mod topk;
 pub mod value;
 pub mod vertex;
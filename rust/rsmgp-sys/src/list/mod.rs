@@ -13,6 +13,10 @@
 // limitations under the License.
 //! All related to the list datatype.
 
+use c_str_macro::c_str;
+use std::ffi::CString;
+
+use crate::map::*;
 use crate::memgraph::*;
 use crate::mgp::*;
 use crate::result::*;
@@ -45,18 +49,21 @@ pub struct ListIterator<'a> {
 }
 
 impl<'a> Iterator for ListIterator<'a> {
-    type Item = Value;
+    type Item = Result<Value>;
 
-    fn next(&mut self) -> Option<Value> {
+    /// Yields [Error::UnableToReadListValue] with the position of the value if it can't be read,
+    /// the iteration continues with the next value.
+    fn next(&mut self) -> Option<Result<Value>> {
         if self.position >= self.list.size() {
             return None;
         }
-        let value = match self.list.value_at(self.position) {
-            Ok(v) => v,
-            Err(_) => panic!("Unable to access the next list value."),
-        };
+        let index = self.position;
         self.position += 1;
-        Some(value)
+        Some(
+            self.list
+                .value_at(index)
+                .map_err(|_| Error::UnableToReadListValue { index }),
+        )
     }
 }
 
@@ -87,7 +94,64 @@ impl List {
         }
     }
 
+    /// Creates a list from the values of the given iterator.
+    ///
+    /// [std::iter::FromIterator] can't be implemented because creating a list requires [Memgraph].
+    pub fn try_from_iter<I>(iter: I, memgraph: &Memgraph) -> Result<List>
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        let iter = iter.into_iter();
+        let list = List::make_empty(iter.size_hint().0 as u64, memgraph)?;
+        for value in iter {
+            list.append_extend(&value)?;
+        }
+        Ok(list)
+    }
+
+    /// Creates a list of `{node_id, score}` maps from the given (node id, score) pairs, e.g., to
+    /// return a whole ranking as a single result field.
+    ///
+    /// Fails with [Error::NonFiniteValue] if any of the scores is NaN or infinite, the same way
+    /// as inserting such score directly into a result record.
+    pub fn from_scored(items: &[(i64, f64)], memgraph: &Memgraph) -> Result<List> {
+        if items.iter().any(|(_, score)| !score.is_finite()) {
+            return Err(Error::NonFiniteValue {
+                name: "score".to_string(),
+            });
+        }
+        let list = List::make_empty(items.len() as u64, memgraph)?;
+        for (node_id, score) in items {
+            let mut map = Map::make_empty(memgraph)?;
+            map.insert(c_str!("node_id"), &Value::Int(*node_id))?;
+            map.insert(c_str!("score"), &Value::Float(*score))?;
+            unsafe {
+                // The map is moved into the value instead of being copied by [MgpValue::make_map].
+                let mgp_value = MgpValue::new(
+                    invoke_mgp_func_with_res!(
+                        *mut mgp_value,
+                        Error::UnableToMakeMapValue,
+                        ffi::mgp_value_make_map,
+                        map.mgp_ptr()
+                    )?,
+                    memgraph,
+                );
+                map.set_mgp_ptr(std::ptr::null_mut());
+                invoke_void_mgp_func_with_res!(
+                    Error::UnableToAppendListValue,
+                    ffi::mgp_list_append,
+                    list.ptr,
+                    mgp_value.mgp_ptr()
+                )?;
+            }
+        }
+        Ok(list)
+    }
+
     /// Creates a new List based on [mgp_list].
+    ///
+    /// `mgp_list_append` stores a deep copy of each element (including nested lists and maps),
+    /// so the new list is owned independently and outlives the original one.
     pub(crate) unsafe fn mgp_copy(ptr: *mut mgp_list, memgraph: &Memgraph) -> Result<List> {
         #[cfg(not(test))]
         assert!(
@@ -113,7 +177,10 @@ impl List {
         unsafe { List::mgp_copy(self.ptr, &self.memgraph) }
     }
 
-    /// Appends value to the list, but if there is no place, returns an error.
+    /// Appends a copy of the value to the list, but if there is no place, returns an error.
+    ///
+    /// The capacity is set by [List::make_empty], use [List::append_extend] if the final size
+    /// isn't known upfront.
     pub fn append(&self, value: &Value) -> Result<()> {
         unsafe {
             let mgp_value = value.to_mgp_value(&self.memgraph)?;
@@ -127,6 +194,8 @@ impl List {
         }
     }
 
+    /// Appends a copy of the value to the list and increases the capacity if needed.
+    ///
     /// In case of a capacity change, the previously contained elements will move in
     /// memory and any references to them will be invalid.
     pub fn append_extend(&self, value: &Value) -> Result<()> {
@@ -165,6 +234,136 @@ impl List {
         }
     }
 
+    /// Returns the value at the given index or `default` if the index is out of bounds.
+    ///
+    /// Useful for procedure arguments, e.g., `args.value_at_or(1, Value::Int(10))?`. Note that
+    /// Memgraph fills in the default values of arguments registered with `define_optional_type!`,
+    /// so this matters only if the number of arguments differs from the registered ones.
+    pub fn value_at_or(&self, index: u64, default: Value) -> Result<Value> {
+        if index >= self.size() {
+            return Ok(default);
+        }
+        self.value_at(index)
+    }
+
+    /// Returns `Ok(None)` if the index is out of bounds, so errors only signal a failure to
+    /// access or convert the value.
+    pub fn try_at(&self, index: u64) -> Result<Option<Value>> {
+        if index >= self.size() {
+            return Ok(None);
+        }
+        self.value_at(index).map(Some)
+    }
+
+    /// Returns the index of the first value equal to the given one, take a look at the
+    /// [PartialEq] implementation of [Value]. Fails if any scanned value can't be read.
+    pub fn index_of(&self, value: &Value) -> Result<Option<usize>> {
+        for index in 0..self.size() {
+            if self.value_at(index)? == *value {
+                return Ok(Some(index as usize));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns `true` if the list contains a value equal to the given one, e.g., if a vertex id
+    /// is inside an allow-list argument.
+    pub fn contains(&self, value: &Value) -> Result<bool> {
+        Ok(self.index_of(value)?.is_some())
+    }
+
+    /// Returns the number of list values as `usize`, e.g., the number of passed procedure
+    /// arguments.
+    pub fn len(&self) -> usize {
+        self.size() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns the integer at the given index or [Error::UnexpectedListValueType] if the value has
+    /// a different type.
+    pub fn int_at(&self, index: u64) -> Result<i64> {
+        match self.value_at(index)? {
+            Value::Int(value) => Ok(value),
+            value => Err(Error::UnexpectedListValueType {
+                index,
+                expected: "INTEGER",
+                got: value.type_name(),
+            }),
+        }
+    }
+
+    /// Returns the double at the given index or [Error::UnexpectedListValueType] if the value has
+    /// a different type.
+    pub fn double_at(&self, index: u64) -> Result<f64> {
+        match self.value_at(index)? {
+            Value::Float(value) => Ok(value),
+            value => Err(Error::UnexpectedListValueType {
+                index,
+                expected: "FLOAT",
+                got: value.type_name(),
+            }),
+        }
+    }
+
+    /// Returns the string at the given index or [Error::UnexpectedListValueType] if the value has
+    /// a different type.
+    pub fn string_at(&self, index: u64) -> Result<CString> {
+        match self.value_at(index)? {
+            Value::String(value) => Ok(value),
+            value => Err(Error::UnexpectedListValueType {
+                index,
+                expected: "STRING",
+                got: value.type_name(),
+            }),
+        }
+    }
+
+    /// Returns the list at the given index or [Error::UnexpectedListValueType] if the value has
+    /// a different type.
+    pub fn list_at(&self, index: u64) -> Result<List> {
+        match self.value_at(index)? {
+            Value::List(value) => Ok(value),
+            value => Err(Error::UnexpectedListValueType {
+                index,
+                expected: "LIST",
+                got: value.type_name(),
+            }),
+        }
+    }
+
+    /// Folds all list values into an accumulator, failing on the first error.
+    ///
+    /// Values are read from the underlying [mgp_list] one by one, only the currently processed
+    /// element is converted into a [Value]. The list is never copied, so the extra memory doesn't
+    /// depend on the list size.
+    pub fn fold<B, F>(&self, init: B, mut func: F) -> Result<B>
+    where
+        F: FnMut(B, Value) -> Result<B>,
+    {
+        let mut accumulator = init;
+        for index in 0..self.size() {
+            accumulator = func(accumulator, self.value_at(index)?)?;
+        }
+        Ok(accumulator)
+    }
+
+    /// Calls the given function on each list value, failing on the first error.
+    ///
+    /// Has the same memory guarantee as [List::fold].
+    pub fn for_each<F>(&self, mut func: F) -> Result<()>
+    where
+        F: FnMut(Value) -> Result<()>,
+    {
+        self.fold((), |_, value| func(value))
+    }
+
+    /// Returns an iterator reading the list values one by one.
+    ///
+    /// Just like [List::fold], the iterator streams the values from the underlying [mgp_list]
+    /// without copying the list.
     pub fn iter(&self) -> Result<ListIterator> {
         Ok(ListIterator {
             list: self,
@@ -172,6 +371,16 @@ impl List {
         })
     }
 
+    /// Returns the underlying [mgp_list] pointer for interop with other C libraries that the safe
+    /// API doesn't cover. The ownership stays with the list.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is valid only while the list is alive and must not be destroyed by the caller.
+    pub unsafe fn as_ptr(&self) -> *mut mgp_list {
+        self.ptr
+    }
+
     pub(crate) fn mgp_ptr(&self) -> *mut mgp_list {
         self.ptr
     }
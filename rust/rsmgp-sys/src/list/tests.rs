@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use serial_test::serial;
+use std::ffi::CStr;
 
 use super::*;
 use crate::memgraph::Memgraph;
@@ -50,6 +51,50 @@ fn test_mgp_copy() {
     });
 }
 
+#[test]
+#[serial]
+fn test_mgp_copy_all_elements() {
+    const LIST_SIZE: u64 = 3;
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = LIST_SIZE;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_list_make_empty_context,
+        |capacity, _, list_ptr_ptr| unsafe {
+            assert_eq!(capacity, LIST_SIZE);
+            (*list_ptr_ptr) = alloc_mgp_list();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    let mut at_calls = 0;
+    let ctx_at = mgp_list_at_context();
+    ctx_at
+        .expect()
+        .times(LIST_SIZE as usize)
+        .returning(move |_, index, _| {
+            assert_eq!(index, at_calls);
+            at_calls += 1;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_append = mgp_list_append_context();
+    ctx_append
+        .expect()
+        .times(LIST_SIZE as usize)
+        .returning(|_, _| mgp_error::MGP_ERROR_NO_ERROR);
+    // Only the copy is destroyed, the original list is owned by the caller.
+    mock_mgp_once!(mgp_list_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let original = unsafe { alloc_mgp_list() };
+        let copy = unsafe { List::mgp_copy(original, &memgraph).unwrap() };
+        assert_ne!(copy.mgp_ptr(), original);
+        unsafe { free(original as *mut c_void) };
+    });
+}
+
 #[test]
 #[serial]
 fn test_make_empty() {
@@ -101,6 +146,89 @@ fn test_append_extend() {
     });
 }
 
+#[test]
+#[serial]
+fn test_append_copies_value() {
+    mock_mgp_once!(mgp_value_make_int_context, |_, _, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_append_context, |_, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    // The list holds a copy, the temporary value is destroyed right away.
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert!(list.append(&Value::Int(1)).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_append_allocation_error() {
+    mock_mgp_once!(mgp_value_make_null_context, |_, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_append_context, |_, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(
+            list.append(&Value::Null).err().unwrap(),
+            Error::UnableToAppendListValue
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_append_extend_copies_value() {
+    mock_mgp_once!(mgp_value_make_int_context, |_, _, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_append_extend_context, |_, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert!(list.append_extend(&Value::Int(1)).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_append_extend_allocation_error() {
+    mock_mgp_once!(mgp_value_make_null_context, |_, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_append_extend_context, |_, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(
+            list.append_extend(&Value::Null).err().unwrap(),
+            Error::UnableToAppendExtendListValue
+        );
+    });
+}
+
 #[test]
 #[serial]
 fn test_size() {
@@ -114,6 +242,26 @@ fn test_size() {
     });
 }
 
+#[test]
+#[serial]
+fn test_is_empty() {
+    let mut sizes = vec![0, 3].into_iter();
+    let ctx_size = mgp_list_size_context();
+    ctx_size
+        .expect()
+        .times(3)
+        .returning(move |_, size_ptr| unsafe {
+            (*size_ptr) = sizes.next().unwrap_or(3);
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(List, |list: &List| {
+        assert!(list.is_empty());
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 3);
+    });
+}
+
 #[test]
 #[serial]
 fn test_capacity() {
@@ -154,3 +302,609 @@ fn test_empty_list_iter() {
         assert!(value.is_none());
     });
 }
+
+#[test]
+#[serial]
+fn test_fold_large_list() {
+    const LIST_SIZE: u64 = 10_000;
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = LIST_SIZE;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_at = mgp_list_at_context();
+    ctx_at
+        .expect()
+        .times(LIST_SIZE as usize)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_get_type = mgp_value_get_type_context();
+    ctx_get_type
+        .expect()
+        .times(LIST_SIZE as usize)
+        .returning(|_, type_ptr| unsafe {
+            (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_get_int = mgp_value_get_int_context();
+    ctx_get_int
+        .expect()
+        .times(LIST_SIZE as usize)
+        .returning(|_, int_ptr| unsafe {
+            (*int_ptr) = 2;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    // Streaming must not copy the list.
+    let ctx_make_empty = mgp_list_make_empty_context();
+    ctx_make_empty.expect().times(0);
+
+    with_dummy!(List, |list: &List| {
+        let sum = list.fold(0, |sum, value| match value {
+            Value::Int(value) => Ok(sum + value),
+            _ => panic!("Expected an integer list value."),
+        });
+        assert_eq!(sum.unwrap(), 2 * LIST_SIZE as i64);
+    });
+}
+
+#[test]
+#[serial]
+fn test_for_each_stops_on_error() {
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 3;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_OUT_OF_RANGE
+    });
+
+    with_dummy!(List, |list: &List| {
+        let mut calls = 0;
+        let result = list.for_each(|_| {
+            calls += 1;
+            Ok(())
+        });
+        assert_eq!(result.err().unwrap(), Error::UnableToAccessListValueByIndex);
+        assert_eq!(calls, 0);
+    });
+}
+
+#[test]
+#[serial]
+fn test_from_scored() {
+    mock_mgp_once!(
+        mgp_list_make_empty_context,
+        |capacity, _, list_ptr_ptr| unsafe {
+            assert_eq!(capacity, 2);
+            (*list_ptr_ptr) = alloc_mgp_list();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    let ctx_make_map = mgp_map_make_empty_context();
+    ctx_make_map
+        .expect()
+        .times(2)
+        .returning(|_, map_ptr_ptr| unsafe {
+            (*map_ptr_ptr) = alloc_mgp_map();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut make_int_calls = 0;
+    let ctx_make_int = mgp_value_make_int_context();
+    ctx_make_int
+        .expect()
+        .times(2)
+        .returning(move |value, _, value_ptr_ptr| unsafe {
+            make_int_calls += 1;
+            assert_eq!(value, make_int_calls);
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut make_double_calls = 0;
+    let ctx_make_double = mgp_value_make_double_context();
+    ctx_make_double
+        .expect()
+        .times(2)
+        .returning(move |value, _, value_ptr_ptr| unsafe {
+            make_double_calls += 1;
+            assert_eq!(value, if make_double_calls == 1 { 0.5 } else { 0.25 });
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut insert_calls = 0;
+    let ctx_map_insert = mgp_map_insert_context();
+    ctx_map_insert
+        .expect()
+        .times(4)
+        .returning(move |_, key, _| unsafe {
+            insert_calls += 1;
+            let expected = if insert_calls % 2 == 1 {
+                c_str!("node_id")
+            } else {
+                c_str!("score")
+            };
+            assert_eq!(CStr::from_ptr(key), expected);
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_make_map_value = mgp_value_make_map_context();
+    ctx_make_map_value
+        .expect()
+        .times(2)
+        .returning(|_, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_append = mgp_list_append_context();
+    ctx_append
+        .expect()
+        .times(2)
+        .returning(|_, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_value_destroy = mgp_value_destroy_context();
+    ctx_value_destroy.expect().times(6).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    // The maps are owned by the list values.
+    let ctx_map_destroy = mgp_map_destroy_context();
+    ctx_map_destroy.expect().times(0);
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 2;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let list = List::from_scored(&[(1, 0.5), (2, 0.25)], &memgraph).unwrap();
+        assert_eq!(list.size(), 2);
+    });
+}
+
+#[test]
+#[serial]
+fn test_from_scored_non_finite_score() {
+    let ctx_make_empty = mgp_list_make_empty_context();
+    ctx_make_empty.expect().times(0);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(
+            List::from_scored(&[(1, 0.5), (2, f64::NAN)], &memgraph)
+                .err()
+                .unwrap(),
+            Error::NonFiniteValue {
+                name: "score".to_string()
+            }
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_value_at_or_present() {
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 2;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_at_context, |_, index, _| {
+        assert_eq!(index, 1);
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_int_context, |_, int_ptr| unsafe {
+        (*int_ptr) = 42;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(List, |list: &List| {
+        match list.value_at_or(1, Value::Int(10)).unwrap() {
+            Value::Int(value) => assert_eq!(value, 42),
+            _ => panic!("Expected an integer value."),
+        }
+    });
+}
+
+#[test]
+#[serial]
+fn test_value_at_or_absent() {
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_at = mgp_list_at_context();
+    ctx_at.expect().times(0);
+
+    with_dummy!(List, |list: &List| {
+        match list.value_at_or(1, Value::Int(10)).unwrap() {
+            Value::Int(value) => assert_eq!(value, 10),
+            _ => panic!("Expected the default value."),
+        }
+    });
+}
+
+#[test]
+#[serial]
+fn test_value_at_or_wrong_count() {
+    let ctx_size = mgp_list_size_context();
+    ctx_size.expect().times(2).returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(list.size(), 0);
+        assert!(matches!(
+            list.value_at_or(3, Value::Null).unwrap(),
+            Value::Null
+        ));
+    });
+}
+
+#[test]
+#[serial]
+fn test_try_at_in_bounds() {
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 2;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_at_context, |_, index, _| {
+        assert_eq!(index, 1);
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_int_context, |_, int_ptr| unsafe {
+        (*int_ptr) = 42;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert!(matches!(list.try_at(1).unwrap(), Some(Value::Int(42))));
+    });
+}
+
+#[test]
+#[serial]
+fn test_try_at_out_of_bounds() {
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_at = mgp_list_at_context();
+    ctx_at.expect().times(0);
+
+    with_dummy!(List, |list: &List| {
+        assert!(list.try_at(1).unwrap().is_none());
+    });
+}
+
+#[test]
+#[serial]
+fn test_try_at_error() {
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(
+            list.try_at(0).err().unwrap(),
+            Error::UnableToAccessListValueByIndex
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_try_from_iter() {
+    mock_mgp_once!(
+        mgp_list_make_empty_context,
+        |capacity, _, list_ptr_ptr| unsafe {
+            assert_eq!(capacity, 3);
+            (*list_ptr_ptr) = alloc_mgp_list();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    let mut make_int_calls = 0;
+    let ctx_make_int = mgp_value_make_int_context();
+    ctx_make_int
+        .expect()
+        .times(3)
+        .returning(move |value, _, value_ptr_ptr| unsafe {
+            assert_eq!(value, make_int_calls);
+            make_int_calls += 1;
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_append_extend = mgp_list_append_extend_context();
+    ctx_append_extend
+        .expect()
+        .times(3)
+        .returning(|_, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy.expect().times(3).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 3;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let list = List::try_from_iter((0..3).map(Value::Int), &memgraph).unwrap();
+        assert_eq!(list.size(), 3);
+    });
+}
+
+#[test]
+#[serial]
+fn test_len() {
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 2;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(list.len(), 2);
+    });
+}
+
+#[test]
+#[serial]
+fn test_int_at() {
+    mock_mgp_once!(mgp_list_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_int_context, |_, int_ptr| unsafe {
+        (*int_ptr) = 42;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(list.int_at(0).unwrap(), 42);
+    });
+}
+
+#[test]
+#[serial]
+fn test_double_at() {
+    mock_mgp_once!(mgp_list_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_DOUBLE;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_double_context, |_, double_ptr| unsafe {
+        (*double_ptr) = 0.85;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(list.double_at(0).unwrap(), 0.85);
+    });
+}
+
+#[test]
+#[serial]
+fn test_string_at() {
+    mock_mgp_once!(mgp_list_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_STRING;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_string_context, |_, string_ptr| unsafe {
+        (*string_ptr) = c_str!("text").as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(list.string_at(0).unwrap().as_c_str(), c_str!("text"));
+    });
+}
+
+#[test]
+#[serial]
+fn test_list_at() {
+    mock_mgp_once!(mgp_list_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_LIST;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_list_context, |_, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_size = mgp_list_size_context();
+    ctx_size.expect().times(2).returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_make_empty_context, |_, _, list_ptr_ptr| unsafe {
+        (*list_ptr_ptr) = alloc_mgp_list();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(List, |list: &List| {
+        let inner = list.list_at(0).unwrap();
+        assert!(inner.is_empty());
+    });
+}
+
+#[test]
+#[serial]
+fn test_int_at_type_mismatch() {
+    mock_mgp_once!(mgp_list_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_DOUBLE;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_double_context, |_, double_ptr| unsafe {
+        (*double_ptr) = 1.5;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(
+            list.int_at(1).unwrap_err(),
+            Error::UnexpectedListValueType {
+                index: 1,
+                expected: "INTEGER",
+                got: "FLOAT",
+            }
+        );
+    });
+}
+
+/// Mocks a list of the given integers, the value pointers are the indices + 1.
+macro_rules! mock_int_list {
+    ($values:expr) => {
+        const VALUES: &[i64] = &$values;
+        let ctx_size = mgp_list_size_context();
+        ctx_size.expect().returning(|_, size_ptr| unsafe {
+            (*size_ptr) = VALUES.len() as u64;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+        let ctx_at = mgp_list_at_context();
+        ctx_at.expect().returning(|_, index, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = (index + 1) as *mut mgp_value;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+        let ctx_get_type = mgp_value_get_type_context();
+        ctx_get_type.expect().returning(|_, type_ptr| unsafe {
+            (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+        let ctx_get_int = mgp_value_get_int_context();
+        ctx_get_int.expect().returning(|value_ptr, int_ptr| unsafe {
+            (*int_ptr) = VALUES[value_ptr as usize - 1];
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    };
+}
+
+#[test]
+#[serial]
+fn test_index_of_present() {
+    mock_int_list!([4, 7, 7]);
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(list.index_of(&Value::Int(7)).unwrap(), Some(1));
+        assert!(list.contains(&Value::Int(4)).unwrap());
+    });
+}
+
+#[test]
+#[serial]
+fn test_index_of_absent() {
+    mock_int_list!([4, 7]);
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(list.index_of(&Value::Int(5)).unwrap(), None);
+        assert!(!list.contains(&Value::Float(4.0)).unwrap());
+    });
+}
+
+#[test]
+#[serial]
+fn test_index_of_empty_list() {
+    mock_int_list!([]);
+
+    with_dummy!(List, |list: &List| {
+        assert_eq!(list.index_of(&Value::Int(5)).unwrap(), None);
+        assert!(!list.contains(&Value::Null).unwrap());
+    });
+}
+
+#[test]
+#[serial]
+fn test_index_of_error() {
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_OUT_OF_RANGE
+    });
+
+    with_dummy!(List, |list: &List| {
+        assert!(list.contains(&Value::Int(1)).is_err());
+    });
+}
+
+#[test]
+#[serial]
+fn test_iter_reports_failed_index() {
+    let ctx_size = mgp_list_size_context();
+    ctx_size.expect().returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 3;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_at = mgp_list_at_context();
+    ctx_at.expect().times(6).returning(|_, index, _| {
+        if index == 2 {
+            mgp_error::MGP_ERROR_OUT_OF_RANGE
+        } else {
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    });
+    let ctx_get_type = mgp_value_get_type_context();
+    ctx_get_type
+        .expect()
+        .times(4)
+        .returning(|_, type_ptr| unsafe {
+            (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_NULL;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(List, |list: &List| {
+        let values: Vec<Result<Value>> = list.iter().unwrap().collect();
+        assert_eq!(values.len(), 3);
+        assert!(values[0].is_ok());
+        assert!(values[1].is_ok());
+        let error = values[2].as_ref().err().unwrap();
+        assert_eq!(*error, Error::UnableToReadListValue { index: 2 });
+        assert_eq!(
+            error.to_string(),
+            "Unable to read the list value at index 2."
+        );
+
+        let collected: Result<Vec<Value>> = list.iter().unwrap().collect();
+        assert!(collected.is_err());
+    });
+}
+
+#[test]
+#[serial]
+fn test_as_ptr() {
+    mock_mgp_once!(mgp_list_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    let memgraph = Memgraph::new_default();
+    unsafe {
+        let ptr = alloc_mgp_list();
+        let list = List::new(ptr, &memgraph);
+        assert_eq!(list.as_ptr(), ptr);
+    }
+    assert_no_leaks();
+}
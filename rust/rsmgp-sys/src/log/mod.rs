@@ -0,0 +1,75 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Writing log messages of the query module.
+//!
+//! The vendored C API doesn't provide access to the Memgraph log, so the messages are written to
+//! the standard error of the Memgraph process, prefixed by the level, e.g. `[WARN] message`.
+//! Messages are formatted on the Rust side, e.g.
+//! `log::info(&format!("Processed {} vertices.", count))?`.
+
+#[cfg(not(test))]
+use std::io::Write;
+
+use crate::memgraph::*;
+use crate::result::*;
+#[cfg(test)]
+use crate::testing::log::write_line;
+
+/// Severity of a log message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
+#[cfg(not(test))]
+fn write_line(line: &str) -> std::io::Result<()> {
+    writeln!(std::io::stderr(), "{}", line)
+}
+
+fn log(level: LogLevel, message: &str) -> Result<()> {
+    write_line(&format!("[{}] {}", level.name(), message)).map_err(|_| Error::UnableToWriteLog)
+}
+
+/// Prefixes the message with the [Memgraph::query_id] of the current procedure call, e.g.
+/// `log::info(&log::with_query_id(memgraph, "Started."))?`.
+pub fn with_query_id(memgraph: &Memgraph, message: &str) -> String {
+    format!("[query {}] {}", memgraph.query_id(), message)
+}
+
+pub fn info(message: &str) -> Result<()> {
+    log(LogLevel::Info, message)
+}
+
+pub fn warn(message: &str) -> Result<()> {
+    log(LogLevel::Warn, message)
+}
+
+pub fn error(message: &str) -> Result<()> {
+    log(LogLevel::Error, message)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,50 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::testing::log::{fail_writes, take_lines};
+
+#[test]
+fn test_info() {
+    assert!(info(&format!("Processed {} vertices.", 3)).is_ok());
+    assert_eq!(take_lines(), vec!["[INFO] Processed 3 vertices."]);
+}
+
+#[test]
+fn test_warn() {
+    assert!(warn("warning").is_ok());
+    assert_eq!(take_lines(), vec!["[WARN] warning"]);
+}
+
+#[test]
+fn test_error() {
+    assert!(error("error").is_ok());
+    assert_eq!(take_lines(), vec!["[ERROR] error"]);
+}
+
+#[test]
+fn test_log_failure() {
+    fail_writes();
+    assert_eq!(info("message").err().unwrap(), Error::UnableToWriteLog);
+    assert!(take_lines().is_empty());
+}
+
+#[test]
+fn test_with_query_id() {
+    let memgraph = Memgraph::new_default();
+    assert_eq!(
+        with_query_id(&memgraph, "Started."),
+        format!("[query {}] Started.", memgraph.query_id())
+    );
+}
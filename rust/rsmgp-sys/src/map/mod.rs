@@ -13,6 +13,7 @@
 // limitations under the License.
 //! All related to the map (dictionary) datatype.
 
+use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 
 use crate::memgraph::*;
@@ -193,10 +194,26 @@ impl Map {
         }
     }
 
+    /// Same as [Map::insert] for Rust string keys. Fails with [Error::UnableToCreateCString] if
+    /// the key contains a nul byte.
+    pub fn insert_str(&self, key: &str, value: &Value) -> Result<()> {
+        let key = CString::new(key).map_err(|_| Error::UnableToCreateCString)?;
+        self.insert(&key, value)
+    }
+
     pub fn size(&self) -> u64 {
         unsafe { invoke_mgp_func!(u64, ffi::mgp_map_size, self.ptr).unwrap() }
     }
 
+    /// Returns the number of map items as `usize`, same as [crate::list::List::len].
+    pub fn len(&self) -> usize {
+        self.size() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
     pub fn at(&self, key: &CStr) -> Result<Value> {
         unsafe {
             let result = invoke_mgp_func_with_res!(
@@ -214,6 +231,64 @@ impl Map {
         }
     }
 
+    pub fn contains_key(&self, key: &CStr) -> Result<bool> {
+        unsafe {
+            let result = invoke_mgp_func_with_res!(
+                *mut mgp_value,
+                Error::UnableToAccessMapValue,
+                ffi::mgp_map_at,
+                self.ptr,
+                key.as_ptr()
+            )?;
+            Ok(!result.is_null())
+        }
+    }
+
+    /// Removes the key from the map, returns `false` if the map doesn't contain it.
+    ///
+    /// The C API can't remove map entries, so the map is rebuilt without the given key, which
+    /// copies all remaining values. Useful to pop known keys from a small configuration map and
+    /// reject the leftovers.
+    pub fn remove(&mut self, key: &CStr) -> Result<bool> {
+        if !self.contains_key(key)? {
+            return Ok(false);
+        }
+        let mut rebuilt = Map::make_empty(&self.memgraph)?;
+        for item in self.iter()? {
+            if item.key.as_c_str() != key {
+                rebuilt.insert(&item.key, &item.value)?;
+            }
+        }
+        // The old map is destroyed together with `rebuilt`.
+        std::mem::swap(&mut self.ptr, &mut rebuilt.ptr);
+        Ok(true)
+    }
+
+    /// Creates a map with the same entries as the given [HashMap].
+    ///
+    /// Keys are inserted in sorted order, so the result doesn't depend on the [HashMap] iteration
+    /// order. Maps with non-string keys have to be converted first, e.g., scores keyed by node id
+    /// with `scores.iter().map(|(id, score)| (id.to_string(), Value::Float(*score))).collect()`.
+    pub fn from_hash_map(map: &HashMap<String, Value>, memgraph: &Memgraph) -> Result<Map> {
+        let mut keys: Vec<&String> = map.keys().collect();
+        keys.sort();
+        let mgp_map = Map::make_empty(memgraph)?;
+        for key in keys {
+            let c_key = CString::new(key.as_bytes()).map_err(|_| Error::UnableToCreateCString)?;
+            mgp_map.insert(&c_key, &map[key])?;
+        }
+        Ok(mgp_map)
+    }
+
+    /// Copies all map entries into a [HashMap]. Keys which aren't valid UTF-8 are converted
+    /// lossily.
+    pub fn to_hash_map(&self) -> Result<HashMap<String, Value>> {
+        Ok(self
+            .iter()?
+            .map(|item| (item.key.to_string_lossy().into_owned(), item.value))
+            .collect())
+    }
+
     pub fn iter(&self) -> Result<MapIterator> {
         unsafe {
             let mgp_iterator = invoke_mgp_func_with_res!(
@@ -227,6 +302,16 @@ impl Map {
         }
     }
 
+    /// Returns the underlying [mgp_map] pointer for interop with other C libraries that the safe
+    /// API doesn't cover. The ownership stays with the map.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is valid only while the map is alive and must not be destroyed by the caller.
+    pub unsafe fn as_ptr(&self) -> *mut mgp_map {
+        self.ptr
+    }
+
     pub(crate) fn mgp_ptr(&self) -> *mut mgp_map {
         self.ptr
     }
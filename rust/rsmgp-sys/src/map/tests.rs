@@ -14,6 +14,7 @@
 
 use c_str_macro::c_str;
 use serial_test::serial;
+use std::ffi::CStr;
 use std::ptr::null_mut;
 
 use super::*;
@@ -88,6 +89,44 @@ fn test_insert() {
     });
 }
 
+#[test]
+#[serial]
+fn test_insert_str() {
+    let ctx_make_null = mgp_value_make_null_context();
+    ctx_make_null
+        .expect()
+        .times(2)
+        .returning(|_, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut inserted = 0;
+    let ctx_insert = mgp_map_insert_context();
+    ctx_insert
+        .expect()
+        .times(2)
+        .returning(move |_, key, _| unsafe {
+            let expected = ["key", "ключ ✓"][inserted];
+            assert_eq!(CStr::from_ptr(key).to_str().unwrap(), expected);
+            inserted += 1;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy
+        .expect()
+        .times(2)
+        .returning(|ptr| unsafe { free(ptr as *mut c_void) });
+
+    with_dummy!(Map, |map: &Map| {
+        assert!(map.insert_str("key", &Value::Null).is_ok());
+        assert!(map.insert_str("ключ ✓", &Value::Null).is_ok());
+        assert_eq!(
+            map.insert_str("k\0ey", &Value::Null).err().unwrap(),
+            Error::UnableToCreateCString
+        );
+    });
+}
+
 #[test]
 #[serial]
 fn test_size() {
@@ -102,6 +141,27 @@ fn test_size() {
     });
 }
 
+#[test]
+#[serial]
+fn test_is_empty() {
+    let mut sizes = vec![0, 2].into_iter();
+    let ctx_size = mgp_map_size_context();
+    ctx_size
+        .expect()
+        .times(4)
+        .returning(move |_, size_ptr| unsafe {
+            (*size_ptr) = sizes.next().unwrap_or(2);
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(Map, |map: &Map| {
+        assert!(map.is_empty());
+        assert!(!map.is_empty());
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.size(), 2);
+    });
+}
+
 #[test]
 #[serial]
 fn test_at() {
@@ -116,6 +176,140 @@ fn test_at() {
     });
 }
 
+#[test]
+#[serial]
+fn test_contains_key() {
+    let mut at_calls = 0;
+    let ctx_at = mgp_map_at_context();
+    ctx_at
+        .expect()
+        .times(2)
+        .returning(move |_, _, value_ptr_ptr| unsafe {
+            at_calls += 1;
+            (*value_ptr_ptr) = if at_calls == 1 {
+                std::ptr::NonNull::dangling().as_ptr()
+            } else {
+                null_mut()
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(Map, |map: &Map| {
+        assert!(map.contains_key(c_str!("present")).unwrap());
+        assert!(!map.contains_key(c_str!("absent")).unwrap());
+    });
+}
+
+#[test]
+#[serial]
+fn test_remove_absent_key() {
+    mock_mgp_once!(mgp_map_at_context, |_, _, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = null_mut();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_make_empty = mgp_map_make_empty_context();
+    ctx_make_empty.expect().times(0);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut map = Map::new(null_mut(), &memgraph);
+        assert!(!map.remove(c_str!("absent")).unwrap());
+    });
+}
+
+#[test]
+#[serial]
+fn test_remove_present_key() {
+    mock_mgp_once!(mgp_map_at_context, |_, _, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = std::ptr::NonNull::dangling().as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_map_make_empty_context, |_, map_ptr_ptr| unsafe {
+        (*map_ptr_ptr) = alloc_mgp_map();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_map_iter_items_context, |_, _, iter_ptr_ptr| unsafe {
+        (*iter_ptr_ptr) = alloc_mgp_map_items_iterator();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_map_items_iterator_get_context,
+        |_, item_ptr_ptr| unsafe {
+            (*item_ptr_ptr) = std::ptr::NonNull::dangling().as_ptr();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    let mut next_calls = 0;
+    let ctx_next = mgp_map_items_iterator_next_context();
+    ctx_next
+        .expect()
+        .times(2)
+        .returning(move |_, item_ptr_ptr| unsafe {
+            next_calls += 1;
+            (*item_ptr_ptr) = if next_calls == 1 {
+                std::ptr::NonNull::dangling().as_ptr()
+            } else {
+                null_mut()
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut key_calls = 0;
+    let ctx_key = mgp_map_item_key_context();
+    ctx_key
+        .expect()
+        .times(2)
+        .returning(move |_, key_ptr_ptr| unsafe {
+            key_calls += 1;
+            (*key_ptr_ptr) = if key_calls == 1 {
+                c_str!("a").as_ptr()
+            } else {
+                c_str!("b").as_ptr()
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_item_value = mgp_map_item_value_context();
+    ctx_item_value
+        .expect()
+        .times(2)
+        .returning(|_, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_get_type = mgp_value_get_type_context();
+    ctx_get_type
+        .expect()
+        .times(2)
+        .returning(|_, type_ptr| unsafe {
+            (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_get_int = mgp_value_get_int_context();
+    ctx_get_int
+        .expect()
+        .times(2)
+        .returning(|_, _| mgp_error::MGP_ERROR_NO_ERROR);
+    mock_mgp_once!(mgp_map_items_iterator_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    // Only the remaining key is copied into the rebuilt map.
+    mock_mgp_once!(mgp_value_make_int_context, |_, _, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_map_insert_context, |_, key, _| unsafe {
+        assert_eq!(CStr::from_ptr(key), c_str!("b"));
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    mock_mgp_once!(mgp_map_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut map = Map::new(null_mut(), &memgraph);
+        assert!(map.remove(c_str!("a")).unwrap());
+        assert!(!map.mgp_ptr().is_null());
+    });
+}
+
 #[test]
 #[serial]
 fn test_empty_map_iter() {
@@ -128,3 +322,133 @@ fn test_empty_map_iter() {
         assert!(iter.is_err());
     });
 }
+
+#[test]
+#[serial]
+fn test_hash_map_round_trip() {
+    mock_mgp_once!(mgp_map_make_empty_context, |_, map_ptr_ptr| unsafe {
+        (*map_ptr_ptr) = alloc_mgp_map();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_make_int = mgp_value_make_int_context();
+    ctx_make_int
+        .expect()
+        .times(2)
+        .returning(|_, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut insert_calls = 0;
+    let ctx_insert = mgp_map_insert_context();
+    ctx_insert
+        .expect()
+        .times(2)
+        .returning(move |_, key, _| unsafe {
+            insert_calls += 1;
+            // Keys are inserted in sorted order.
+            let expected = if insert_calls == 1 {
+                c_str!("a")
+            } else {
+                c_str!("b")
+            };
+            assert_eq!(CStr::from_ptr(key), expected);
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_value_destroy = mgp_value_destroy_context();
+    ctx_value_destroy.expect().times(2).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    mock_mgp_once!(mgp_map_iter_items_context, |_, _, iter_ptr_ptr| unsafe {
+        (*iter_ptr_ptr) = alloc_mgp_map_items_iterator();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_map_items_iterator_get_context,
+        |_, item_ptr_ptr| unsafe {
+            (*item_ptr_ptr) = std::ptr::NonNull::dangling().as_ptr();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    let mut next_calls = 0;
+    let ctx_next = mgp_map_items_iterator_next_context();
+    ctx_next
+        .expect()
+        .times(2)
+        .returning(move |_, item_ptr_ptr| unsafe {
+            next_calls += 1;
+            (*item_ptr_ptr) = if next_calls == 1 {
+                std::ptr::NonNull::dangling().as_ptr()
+            } else {
+                null_mut()
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut key_calls = 0;
+    let ctx_key = mgp_map_item_key_context();
+    ctx_key
+        .expect()
+        .times(2)
+        .returning(move |_, key_ptr_ptr| unsafe {
+            key_calls += 1;
+            (*key_ptr_ptr) = if key_calls == 1 {
+                c_str!("a").as_ptr()
+            } else {
+                c_str!("b").as_ptr()
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_item_value = mgp_map_item_value_context();
+    ctx_item_value
+        .expect()
+        .times(2)
+        .returning(|_, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_get_type = mgp_value_get_type_context();
+    ctx_get_type
+        .expect()
+        .times(2)
+        .returning(|_, type_ptr| unsafe {
+            (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut get_int_calls = 0;
+    let ctx_get_int = mgp_value_get_int_context();
+    ctx_get_int
+        .expect()
+        .times(2)
+        .returning(move |_, int_ptr| unsafe {
+            get_int_calls += 1;
+            (*int_ptr) = get_int_calls;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    mock_mgp_once!(mgp_map_items_iterator_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    mock_mgp_once!(mgp_map_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut input = HashMap::new();
+        input.insert("b".to_string(), Value::Int(2));
+        input.insert("a".to_string(), Value::Int(1));
+        let map = Map::from_hash_map(&input, &memgraph).unwrap();
+        let output = map.to_hash_map().unwrap();
+        assert_eq!(output, input);
+    });
+}
+
+#[test]
+#[serial]
+fn test_as_ptr() {
+    mock_mgp_once!(mgp_map_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    let memgraph = Memgraph::new_default();
+    unsafe {
+        let ptr = alloc_mgp_map();
+        let map = Map::new(ptr, &memgraph);
+        assert_eq!(map.as_ptr(), ptr);
+    }
+    assert_no_leaks();
+}
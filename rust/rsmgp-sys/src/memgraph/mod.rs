@@ -13,13 +13,20 @@
 // limitations under the License.
 //! Abstraction to interact with Memgraph.
 
-use std::ffi::CStr;
+use std::cell::Cell;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
+use crate::edge::*;
+use crate::id::*;
 use crate::list::*;
 use crate::mgp::*;
 use crate::result::*;
 use crate::rsmgp::*;
+use crate::value::*;
 use crate::vertex::*;
 // Required here, if not present, tests linking fails.
 #[double]
@@ -214,6 +221,137 @@ pub(crate) use invoke_mgp_func_with_res;
 pub(crate) use invoke_void_mgp_func;
 pub(crate) use invoke_void_mgp_func_with_res;
 
+/// Number of rows emitted by [Memgraph::result_record_batch] between two abort checks.
+pub const RESULT_BATCH_ABORT_CHECK_INTERVAL: u64 = 1000;
+
+/// Top level C function that represents a procedure.
+pub type ProcedurePtr =
+    extern "C" fn(*mut mgp_list, *mut mgp_graph, *mut mgp_result, *mut mgp_memory);
+
+/// Argument declared during the procedure registration, take a look at [Memgraph::arguments].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArgumentType {
+    pub name: CString,
+    pub types: Vec<Type>,
+    pub optional: bool,
+    /// Default of an optional argument formatted by [Value] `Display` (e.g., `1000`), useful to
+    /// log the applied defaults. `None` for the required arguments.
+    pub default_value: Option<String>,
+}
+
+/// Signature declared during the procedure registration.
+struct ProcedureSignature {
+    arguments: Vec<ArgumentType>,
+    result_fields: Vec<CString>,
+}
+
+/// Signatures of all procedures registered by the module, keyed by the address of the procedure
+/// function. The C API doesn't allow reading the signature back from [mgp_proc].
+static PROCEDURE_SIGNATURES: Mutex<Vec<(usize, Arc<ProcedureSignature>)>> = Mutex::new(Vec::new());
+
+fn find_procedure_signature(proc_ptr: ProcedurePtr) -> Option<Arc<ProcedureSignature>> {
+    let key = proc_ptr as usize;
+    let procedures = PROCEDURE_SIGNATURES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    procedures
+        .iter()
+        .find(|(registered, _)| *registered == key)
+        .map(|(_, signature)| signature.clone())
+}
+
+/// Forgets the signatures of all registered procedures, so that a test doesn't see the procedures
+/// registered by other tests.
+#[cfg(test)]
+pub(crate) fn clear_procedure_signatures() {
+    PROCEDURE_SIGNATURES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
+fn store_procedure_signature(
+    proc_ptr: ProcedurePtr,
+    required_arg_types: &[NamedType],
+    optional_arg_types: &[OptionalNamedType],
+    result_field_types: &[NamedType],
+) {
+    let required = required_arg_types.iter().map(|arg| ArgumentType {
+        name: arg.name.to_owned(),
+        types: arg.types.to_vec(),
+        optional: false,
+        default_value: None,
+    });
+    let optional = optional_arg_types.iter().map(|arg| ArgumentType {
+        name: arg.name.to_owned(),
+        types: arg.types.to_vec(),
+        optional: true,
+        default_value: arg.default.to_value().ok().map(|value| value.to_string()),
+    });
+    let signature = ProcedureSignature {
+        arguments: required.chain(optional).collect(),
+        result_fields: result_field_types
+            .iter()
+            .map(|field| field.name.to_owned())
+            .collect(),
+    };
+
+    let key = proc_ptr as usize;
+    let mut procedures = PROCEDURE_SIGNATURES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    procedures.retain(|(registered, _)| *registered != key);
+    procedures.push((key, Arc::new(signature)));
+}
+
+/// Registers the arguments and result fields of the given procedure.
+unsafe fn add_procedure_signature(
+    procedure: *mut mgp_proc,
+    required_arg_types: &[NamedType],
+    optional_arg_types: &[OptionalNamedType],
+    result_field_types: &[NamedType],
+) -> Result<()> {
+    for required_type in required_arg_types {
+        let mgp_type = resolve_mgp_type(&required_type.types);
+        if ffi::mgp_proc_add_arg(procedure, required_type.name.as_ptr(), mgp_type)
+            != mgp_error::MGP_ERROR_NO_ERROR
+        {
+            return Err(Error::UnableToAddRequiredArguments);
+        }
+    }
+
+    for optional_input in optional_arg_types {
+        let mgp_type = resolve_mgp_type(&optional_input.types);
+
+        if ffi::mgp_proc_add_opt_arg(
+            procedure,
+            optional_input.name.as_ptr(),
+            mgp_type,
+            optional_input.default.mgp_ptr(),
+        ) != mgp_error::MGP_ERROR_NO_ERROR
+        {
+            return Err(Error::UnableToAddOptionalArguments);
+        }
+    }
+
+    for result_field in result_field_types {
+        let mgp_type = resolve_mgp_type(&result_field.types);
+        if result_field.deprecated {
+            if ffi::mgp_proc_add_deprecated_result(procedure, result_field.name.as_ptr(), mgp_type)
+                != mgp_error::MGP_ERROR_NO_ERROR
+            {
+                return Err(Error::UnableToAddDeprecatedReturnType);
+            }
+        } else if ffi::mgp_proc_add_result(procedure, result_field.name.as_ptr(), mgp_type)
+            != mgp_error::MGP_ERROR_NO_ERROR
+        {
+            return Err(Error::UnableToAddReturnType);
+        }
+    }
+
+    Ok(())
+}
+
 /// Combines the given array of types from left to right to construct [mgp_type]. E.g., if the
 /// input is [Type::List, Type::Int], the constructed [mgp_type] is going to be list of integers.
 fn resolve_mgp_type(types: &[Type]) -> *mut mgp_type {
@@ -259,6 +397,18 @@ pub struct Memgraph {
     result: *mut mgp_result,
     memory: *mut mgp_memory,
     module: *mut mgp_module,
+    signature: Option<Arc<ProcedureSignature>>,
+    records_emitted: Rc<Cell<u64>>,
+    query_id: u64,
+}
+
+/// Source of [Memgraph::query_id], shared by all procedures of the loaded module.
+static NEXT_QUERY_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Takes the next [Memgraph::query_id]. Called once per procedure call by
+/// [crate::define_procedure], required to be public for the same reason as [Memgraph::new].
+pub fn next_query_id() -> u64 {
+    NEXT_QUERY_ID.fetch_add(1, Ordering::Relaxed)
 }
 
 impl Memgraph {
@@ -279,6 +429,9 @@ impl Memgraph {
             result,
             memory,
             module,
+            signature: None,
+            records_emitted: Rc::new(Cell::new(0)),
+            query_id: next_query_id(),
         }
     }
 
@@ -291,15 +444,93 @@ impl Memgraph {
             result: std::ptr::null_mut(),
             memory: std::ptr::null_mut(),
             module: std::ptr::null_mut(),
+            signature: None,
+            records_emitted: Rc::new(Cell::new(0)),
+            query_id: next_query_id(),
         }
     }
 
+    /// Sets a signature with the given result fields and no arguments, so that tests don't have to
+    /// register a procedure.
+    #[cfg(test)]
+    pub(crate) fn with_result_fields(mut self, fields: &[&CStr]) -> Memgraph {
+        self.signature = Some(Arc::new(ProcedureSignature {
+            arguments: vec![],
+            result_fields: fields.iter().map(|field| (*field).to_owned()).collect(),
+        }));
+        self
+    }
+
+    /// Sets the currently executing procedure. Called by [crate::define_procedure], required to
+    /// be public for the same reason as [Memgraph::new].
+    ///
+    /// The signature of the procedure is looked up once here and shared by all copies of the
+    /// object, so result inserts don't touch the registry of all procedures.
+    pub fn with_procedure(mut self, proc_ptr: ProcedurePtr) -> Memgraph {
+        self.signature = find_procedure_signature(proc_ptr);
+        self
+    }
+
+    /// Sets the id of the procedure call, so that all objects created during the call share it
+    /// (e.g., the one passing a panic message to Memgraph). Called by [crate::define_procedure],
+    /// required to be public for the same reason as [Memgraph::new].
+    pub fn with_query_id(mut self, query_id: u64) -> Memgraph {
+        self.query_id = query_id;
+        self
+    }
+
+    /// Returns the id correlating everything done within one procedure call, e.g. to tell apart
+    /// log messages of concurrent queries (see [crate::log::with_query_id]).
+    ///
+    /// The C API doesn't expose a transaction or query id, so each procedure call takes the next
+    /// number of a module-wide counter (see [next_query_id]). Copies of the object (including the
+    /// ones held by graph objects) keep the id.
+    pub fn query_id(&self) -> u64 {
+        self.query_id
+    }
+
     /// Arguments passed to the procedure call.
     pub fn args(&self) -> Result<List> {
         // TODO(gitbuda): Avoid list copy when accessing procedure arguments.
         unsafe { List::mgp_copy(self.args_ptr(), &self) }
     }
 
+    /// Returns the arguments declared for the currently executing procedure, required arguments
+    /// first, each group in the declaration order.
+    ///
+    /// Fails with [Error::UnableToFindProcedureArguments] if the procedure wasn't registered
+    /// with [Memgraph::add_read_procedure] or [Memgraph::add_write_procedure].
+    pub fn arguments(&self) -> Result<Vec<ArgumentType>> {
+        self.with_signature(|signature| signature.arguments.clone())
+            .ok_or(Error::UnableToFindProcedureArguments)
+    }
+
+    /// Returns the result fields declared for the currently executing procedure in the declaration
+    /// order.
+    ///
+    /// Fails with [Error::UnableToFindProcedureResultFields] if the procedure wasn't registered
+    /// with [Memgraph::add_read_procedure] or [Memgraph::add_write_procedure].
+    pub fn result_fields(&self) -> Result<Vec<CString>> {
+        self.with_signature(|signature| signature.result_fields.clone())
+            .ok_or(Error::UnableToFindProcedureResultFields)
+    }
+
+    /// Returns `false` only if the signature of the currently executing procedure is known and
+    /// doesn't declare the given result field.
+    pub(crate) fn is_declared_result_field(&self, field: &CStr) -> bool {
+        self.with_signature(|signature| {
+            signature
+                .result_fields
+                .iter()
+                .any(|declared| declared.as_c_str() == field)
+        })
+        .unwrap_or(true)
+    }
+
+    fn with_signature<T>(&self, f: impl FnOnce(&ProcedureSignature) -> T) -> Option<T> {
+        self.signature.as_deref().map(f)
+    }
+
     /// Returns pointer to the object with all arguments passed to the procedure call.
     pub(crate) fn args_ptr(&self) -> *mut mgp_list {
         self.args
@@ -325,6 +556,10 @@ impl Memgraph {
         self.module
     }
 
+    /// Returns an iterator over all graph vertices.
+    ///
+    /// Each yielded [Vertex] is a copy owned by the caller. The underlying
+    /// [mgp_vertices_iterator] is destroyed once the returned iterator is dropped.
     pub fn vertices_iter(&self) -> Result<VerticesIterator> {
         unsafe {
             let mgp_iterator = invoke_mgp_func!(
@@ -340,19 +575,69 @@ impl Memgraph {
         }
     }
 
-    pub fn vertex_by_id(&self, id: i64) -> Result<Vertex> {
+    /// Returns an iterator over all graph edges.
+    ///
+    /// The outgoing edges of each vertex are yielded, so every edge is yielded exactly once, also
+    /// if the graph is interpreted as undirected. Each yielded [Edge] is a copy owned by the
+    /// caller.
+    pub fn edges_iter(&self) -> Result<GraphEdgesIterator> {
+        Ok(GraphEdgesIterator::new(self.vertices_iter()?))
+    }
+
+    /// Returns the number of graph vertices.
+    ///
+    /// The C API doesn't expose the count, so all vertices are iterated (and copied) once. In the
+    /// analytical storage mode other transactions can change the graph meanwhile, so the count is
+    /// only an estimate, take a look at [Memgraph::is_transactional].
+    pub fn approximate_vertex_count(&self) -> Result<usize> {
+        let mut count = 0;
+        for vertex in self.vertices_iter()? {
+            vertex?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Returns the number of graph edges.
+    ///
+    /// Iterates over the outgoing edges of all vertices, which is as expensive as a traversal of
+    /// the whole graph. The same as [Memgraph::approximate_vertex_count], the count is only an
+    /// estimate in the analytical storage mode.
+    pub fn approximate_edge_count(&self) -> Result<usize> {
+        let mut count = 0;
+        for vertex in self.vertices_iter()? {
+            for edge in vertex?.out_edges()? {
+                edge?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Returns the vertex with the given id, or an error if there is no such vertex.
+    pub fn vertex_by_id(&self, id: impl Into<Id>) -> Result<Vertex> {
+        self.try_vertex_by_id(id)?
+            .ok_or(Error::UnableToFindVertexById)
+    }
+
+    /// Returns the vertex with the given id, or `None` if there is no such vertex (e.g. it was
+    /// deleted). Fails only if the vertex can't be allocated.
+    pub fn try_vertex_by_id(&self, id: impl Into<Id>) -> Result<Option<Vertex>> {
         unsafe {
-            let mgp_vertex_ptr = invoke_mgp_func!(
+            let mgp_vertex_ptr = invoke_mgp_func_with_res!(
                 *mut mgp_vertex,
+                Error::UnableToReturnVertexByIdAllocationError,
                 ffi::mgp_graph_get_vertex_by_id,
                 self.graph_ptr(),
-                mgp_vertex_id { as_int: id },
+                mgp_vertex_id {
+                    as_int: id.into().0
+                },
                 self.memory_ptr()
-            );
-            if mgp_vertex_ptr.is_err() {
-                return Err(Error::UnableToFindVertexById);
+            )?;
+            if mgp_vertex_ptr.is_null() {
+                return Ok(None);
             }
-            Ok(Vertex::new(mgp_vertex_ptr.unwrap(), &self))
+            Ok(Some(Vertex::new(mgp_vertex_ptr, &self)))
         }
     }
 
@@ -364,6 +649,82 @@ impl Memgraph {
         ResultRecord::create(self)
     }
 
+    /// Creates a new result record tagged as [RecordKind::Result].
+    ///
+    /// Used by procedures emitting progress records, take a look at [RecordKind].
+    pub fn tagged_result_record(&self) -> Result<ResultRecord> {
+        ResultRecord::create_with_kind(self, RecordKind::Result, 0.0, &[])
+    }
+
+    /// Emits a progress record tagged as [RecordKind::Progress].
+    ///
+    /// `result_fields` are the remaining (nullable) result fields, they are set to null.
+    pub fn emit_progress(&self, progress: f64, result_fields: &[&CStr]) -> Result<()> {
+        ResultRecord::create_with_kind(self, RecordKind::Progress, progress, result_fields)?;
+        Ok(())
+    }
+
+    /// Creates one result record per item and fills it by calling `insert`, returns the number of
+    /// created records.
+    ///
+    /// By default (`partial_results` is `false`) the first error is returned and Memgraph discards
+    /// all results of the call. If `partial_results` is `true`, a failure to create a record (e.g.
+    /// out of memory after many rows) is only logged as a warning and the records emitted so far
+    /// are returned. Errors returned by `insert` always fail the call.
+    pub fn emit_records<I, F>(&self, items: I, partial_results: bool, mut insert: F) -> Result<u64>
+    where
+        I: IntoIterator,
+        F: FnMut(&ResultRecord, I::Item) -> Result<()>,
+    {
+        let mut emitted = 0;
+        for item in items {
+            let record = match self.result_record() {
+                Ok(record) => record,
+                Err(e) if partial_results => {
+                    // The emitted records are returned even if the warning can't be logged.
+                    let _ =
+                        crate::log::warn(&format!("{} Returning {} partial results.", e, emitted));
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+            insert(&record, item)?;
+            emitted += 1;
+        }
+        Ok(emitted)
+    }
+
+    /// Creates one result record per row, the row values are inserted under `fields` in the same
+    /// order. Returns the number of created records.
+    ///
+    /// Calls [Memgraph::check_abort] once per [RESULT_BATCH_ABORT_CHECK_INTERVAL] rows, so that
+    /// emitting a large result can be terminated.
+    pub fn result_record_batch<I, R>(&self, fields: &[&CStr], rows: I) -> Result<u64>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[Value]>,
+    {
+        let mut emitted = 0;
+        for row in rows {
+            if emitted % RESULT_BATCH_ABORT_CHECK_INTERVAL == 0 {
+                self.check_abort()?;
+            }
+            let values = row.as_ref();
+            if values.len() != fields.len() {
+                return Err(Error::UnexpectedResultRowLength {
+                    expected: fields.len(),
+                    got: values.len(),
+                });
+            }
+            let record = self.result_record()?;
+            for (field, value) in fields.iter().zip(values) {
+                record.insert_mgp_value(field, &value.to_mgp_value(self)?)?;
+            }
+            emitted += 1;
+        }
+        Ok(emitted)
+    }
+
     /// Registers a new read procedure.
     ///
     /// * `proc_ptr` - Identifier of the top level C function that represents the procedure.
@@ -374,9 +735,19 @@ impl Memgraph {
     ///    array of [Type]s, and default value.
     /// * `result_field_types` - An array of all [NamedType]s, each one defined by name and an
     ///    array of [Type]s.
+    ///
+    /// Result fields are fixed at registration time, Memgraph doesn't support result fields
+    /// computed from the procedure arguments. If the returned columns depend on the input (e.g.
+    /// one column per requested feature), register a single [Type::Map] result field and insert a
+    /// [crate::map::Map] keyed by the column names with [ResultRecord::insert_map].
+    ///
+    /// Memgraph checks the procedure arguments against the registered types before the procedure
+    /// is called, so the procedure body doesn't have to validate them again. Only arguments
+    /// registered with a broad type (e.g. [Type::Any] or [Type::Number]) need to be matched on
+    /// every possible [crate::value::Value] variant.
     pub fn add_read_procedure(
         &self,
-        proc_ptr: extern "C" fn(*mut mgp_list, *mut mgp_graph, *mut mgp_result, *mut mgp_memory),
+        proc_ptr: ProcedurePtr,
         name: &CStr,
         required_arg_types: &[NamedType],
         optional_arg_types: &[OptionalNamedType],
@@ -393,50 +764,212 @@ impl Memgraph {
             if maybe_procedure.is_err() {
                 return Err(Error::UnableToRegisterReadProcedure);
             }
-            let procedure = maybe_procedure.unwrap();
-
-            for required_type in required_arg_types {
-                let mgp_type = resolve_mgp_type(&required_type.types);
-                if ffi::mgp_proc_add_arg(procedure, required_type.name.as_ptr(), mgp_type)
-                    != mgp_error::MGP_ERROR_NO_ERROR
-                {
-                    return Err(Error::UnableToAddRequiredArguments);
-                }
+            add_procedure_signature(
+                maybe_procedure.unwrap(),
+                required_arg_types,
+                optional_arg_types,
+                result_field_types,
+            )?;
+        }
+        store_procedure_signature(
+            proc_ptr,
+            required_arg_types,
+            optional_arg_types,
+            result_field_types,
+        );
+        Ok(())
+    }
+
+    /// Registers a new write procedure, the arguments are the same as in
+    /// [Memgraph::add_read_procedure].
+    ///
+    /// Only write procedures get a mutable graph, the graph modifying helpers (e.g.
+    /// [Memgraph::create_vertex] or [Vertex::set_property]) fail with [Error::ImmutableGraphError]
+    /// when called from a read procedure, take a look at [Memgraph::check_mutable].
+    pub fn add_write_procedure(
+        &self,
+        proc_ptr: ProcedurePtr,
+        name: &CStr,
+        required_arg_types: &[NamedType],
+        optional_arg_types: &[OptionalNamedType],
+        result_field_types: &[NamedType],
+    ) -> Result<()> {
+        unsafe {
+            let procedure = invoke_mgp_func_with_res!(
+                *mut mgp_proc,
+                Error::UnableToRegisterWriteProcedure,
+                ffi::mgp_module_add_write_procedure,
+                self.module_ptr(),
+                name.as_ptr(),
+                Some(proc_ptr)
+            )?;
+            add_procedure_signature(
+                procedure,
+                required_arg_types,
+                optional_arg_types,
+                result_field_types,
+            )?;
+        }
+        store_procedure_signature(
+            proc_ptr,
+            required_arg_types,
+            optional_arg_types,
+            result_field_types,
+        );
+        Ok(())
+    }
+
+    /// Returns the number of result records created during the current procedure call, progress
+    /// records included.
+    ///
+    /// The counter is shared between the clones, and a new [Memgraph] object is created for each
+    /// procedure call, so the count always starts from zero.
+    pub fn records_emitted(&self) -> u64 {
+        self.records_emitted.get()
+    }
+
+    pub(crate) fn count_emitted_record(&self) {
+        self.records_emitted.set(self.records_emitted.get() + 1);
+    }
+
+    /// Returns `true` if the graph can be modified, i.e., the procedure runs as a write procedure.
+    ///
+    /// Procedures registered with [Memgraph::add_read_procedure] always get an immutable graph.
+    pub fn is_mutable(&self) -> bool {
+        unsafe {
+            invoke_mgp_func!(::std::os::raw::c_int, ffi::mgp_graph_is_mutable, self.graph).unwrap()
+                != 0
+        }
+    }
+
+    /// Returns [Error::ImmutableGraphError] if [Memgraph::is_mutable] is `false`. Should be called
+    /// by all helpers which modify the graph before calling into Memgraph.
+    pub fn check_mutable(&self) -> Result<()> {
+        if !self.is_mutable() {
+            return Err(Error::ImmutableGraphError);
+        }
+        Ok(())
+    }
+
+    /// Adds a new vertex to the graph and returns it.
+    ///
+    /// Fails early with [Error::ImmutableGraphError] if the procedure can't modify the graph.
+    pub fn create_vertex(&self) -> Result<Vertex> {
+        self.check_mutable()?;
+        unsafe {
+            match invoke_mgp_func!(
+                *mut mgp_vertex,
+                ffi::mgp_graph_create_vertex,
+                self.graph_ptr(),
+                self.memory_ptr()
+            ) {
+                Ok(vertex) => Ok(Vertex::new(vertex, self)),
+                Err(MgpError::ImmutableObject) => Err(Error::ImmutableGraphError),
+                Err(_) => Err(Error::UnableToCreateVertex),
             }
+        }
+    }
 
-            for optional_input in optional_arg_types {
-                let mgp_type = resolve_mgp_type(&optional_input.types);
-
-                if ffi::mgp_proc_add_opt_arg(
-                    procedure,
-                    optional_input.name.as_ptr(),
-                    mgp_type,
-                    optional_input.default.mgp_ptr(),
-                ) != mgp_error::MGP_ERROR_NO_ERROR
-                {
-                    return Err(Error::UnableToAddOptionalArguments);
-                }
+    /// Adds a new directed edge of the given type between the two vertices and returns it.
+    ///
+    /// Both vertices have to come from the graph of this [Memgraph] object, otherwise
+    /// [Error::UnableToCreateEdgeBetweenGraphs] is returned. Fails early with
+    /// [Error::ImmutableGraphError] if the procedure can't modify the graph.
+    pub fn create_edge(&self, from: &Vertex, to: &Vertex, edge_type: &CStr) -> Result<Edge> {
+        if from.memgraph().graph_ptr() != self.graph_ptr()
+            || to.memgraph().graph_ptr() != self.graph_ptr()
+        {
+            return Err(Error::UnableToCreateEdgeBetweenGraphs);
+        }
+        self.check_mutable()?;
+        unsafe {
+            let mgp_edge_type = mgp_edge_type {
+                name: edge_type.as_ptr(),
+            };
+            match invoke_mgp_func!(
+                *mut mgp_edge,
+                ffi::mgp_graph_create_edge,
+                self.graph_ptr(),
+                from.mgp_ptr(),
+                to.mgp_ptr(),
+                mgp_edge_type,
+                self.memory_ptr()
+            ) {
+                Ok(edge) => Ok(Edge::new(edge, self)),
+                Err(MgpError::ImmutableObject) => Err(Error::ImmutableGraphError),
+                Err(_) => Err(Error::UnableToCreateEdge),
             }
+        }
+    }
 
-            for result_field in result_field_types {
-                let mgp_type = resolve_mgp_type(&result_field.types);
-                if result_field.deprecated {
-                    if ffi::mgp_proc_add_deprecated_result(
-                        procedure,
-                        result_field.name.as_ptr(),
-                        mgp_type,
-                    ) != mgp_error::MGP_ERROR_NO_ERROR
-                    {
-                        return Err(Error::UnableToAddDeprecatedReturnType);
-                    }
-                } else if ffi::mgp_proc_add_result(procedure, result_field.name.as_ptr(), mgp_type)
-                    != mgp_error::MGP_ERROR_NO_ERROR
-                {
-                    return Err(Error::UnableToAddReturnType);
-                }
+    /// Deletes the vertex from the graph.
+    ///
+    /// Fails with [Error::UnableToDeleteVertexWithEdges] if the vertex still has edges, take a
+    /// look at [Memgraph::detach_delete_vertex]. Fails early with [Error::ImmutableGraphError] if
+    /// the procedure can't modify the graph.
+    pub fn delete_vertex(&self, vertex: &Vertex) -> Result<()> {
+        self.check_mutable()?;
+        unsafe {
+            match invoke_void_mgp_func!(
+                ffi::mgp_graph_delete_vertex,
+                self.graph_ptr(),
+                vertex.mgp_ptr()
+            ) {
+                Ok(()) => Ok(()),
+                Err(MgpError::ImmutableObject) => Err(Error::ImmutableGraphError),
+                Err(MgpError::LogicError) => Err(Error::UnableToDeleteVertexWithEdges),
+                Err(_) => Err(Error::UnableToDeleteVertex),
             }
+        }
+    }
 
-            Ok(())
+    /// Deletes the vertex together with all of its edges from the graph.
+    ///
+    /// Fails early with [Error::ImmutableGraphError] if the procedure can't modify the graph.
+    pub fn detach_delete_vertex(&self, vertex: &Vertex) -> Result<()> {
+        self.check_mutable()?;
+        unsafe {
+            match invoke_void_mgp_func!(
+                ffi::mgp_graph_detach_delete_vertex,
+                self.graph_ptr(),
+                vertex.mgp_ptr()
+            ) {
+                Ok(()) => Ok(()),
+                Err(MgpError::ImmutableObject) => Err(Error::ImmutableGraphError),
+                Err(_) => Err(Error::UnableToDeleteVertex),
+            }
+        }
+    }
+
+    /// Deletes the edge from the graph.
+    ///
+    /// Fails early with [Error::ImmutableGraphError] if the procedure can't modify the graph.
+    pub fn delete_edge(&self, edge: &Edge) -> Result<()> {
+        self.check_mutable()?;
+        unsafe {
+            match invoke_void_mgp_func!(
+                ffi::mgp_graph_delete_edge,
+                self.graph_ptr(),
+                edge.mgp_ptr()
+            ) {
+                Ok(()) => Ok(()),
+                Err(MgpError::ImmutableObject) => Err(Error::ImmutableGraphError),
+                Err(_) => Err(Error::UnableToDeleteEdge),
+            }
+        }
+    }
+
+    /// Returns `true` if the graph is in the transactional storage mode. In the analytical mode
+    /// changes from other transactions are visible, so vertices and edges can be missing.
+    pub fn is_transactional(&self) -> bool {
+        unsafe {
+            invoke_mgp_func!(
+                ::std::os::raw::c_int,
+                ffi::mgp_graph_is_transactional,
+                self.graph
+            )
+            .unwrap()
+                != 0
         }
     }
 
@@ -450,6 +983,82 @@ impl Memgraph {
     pub fn must_abort(&self) -> bool {
         unsafe { ffi::mgp_must_abort(self.graph_ptr()) != 0 }
     }
+
+    /// Returns [Error::ProcedureAborted] if [Memgraph::must_abort] is `true`, so that long loops
+    /// can stop with `memgraph.check_abort()?` instead of returning partial results.
+    pub fn check_abort(&self) -> Result<()> {
+        if self.must_abort() {
+            return Err(Error::ProcedureAborted);
+        }
+        Ok(())
+    }
+}
+
+/// Read procedure waiting for the registration inside a [ProcedureSet].
+struct ReadProcedureDefinition<'a> {
+    proc_ptr: ProcedurePtr,
+    name: &'a CStr,
+    required_arg_types: &'a [NamedType<'a>],
+    optional_arg_types: &'a [OptionalNamedType<'a>],
+    result_field_types: &'a [NamedType<'a>],
+}
+
+/// Group of related read procedures registered together inside [crate::init_module].
+///
+/// The argument and result types are borrowed, so related procedures (e.g. a computation and a
+/// getter of its results) can share the same type arrays.
+///
+/// Example
+///
+/// ```no run
+/// let results = [define_type!("node_id", Type::Int), define_type!("score", Type::Double)];
+/// ProcedureSet::new()
+///     .read_procedure(compute, c_str!("compute"), &[], &[], &results)
+///     .read_procedure(get, c_str!("get"), &[], &[], &results)
+///     .register(&memgraph)?;
+/// ```
+#[derive(Default)]
+pub struct ProcedureSet<'a> {
+    procedures: Vec<ReadProcedureDefinition<'a>>,
+}
+
+impl<'a> ProcedureSet<'a> {
+    pub fn new() -> ProcedureSet<'a> {
+        ProcedureSet::default()
+    }
+
+    /// Adds a read procedure, the arguments are the same as in [Memgraph::add_read_procedure].
+    pub fn read_procedure(
+        mut self,
+        proc_ptr: ProcedurePtr,
+        name: &'a CStr,
+        required_arg_types: &'a [NamedType<'a>],
+        optional_arg_types: &'a [OptionalNamedType<'a>],
+        result_field_types: &'a [NamedType<'a>],
+    ) -> ProcedureSet<'a> {
+        self.procedures.push(ReadProcedureDefinition {
+            proc_ptr,
+            name,
+            required_arg_types,
+            optional_arg_types,
+            result_field_types,
+        });
+        self
+    }
+
+    /// Registers all procedures in the order they were added, stops on the first error.
+    pub fn register(&self, memgraph: &Memgraph) -> Result<()> {
+        for procedure in &self.procedures {
+            memgraph.add_read_procedure(
+                procedure.proc_ptr,
+                procedure.name,
+                procedure.required_arg_types,
+                procedure.optional_arg_types,
+                procedure.result_field_types,
+            )?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
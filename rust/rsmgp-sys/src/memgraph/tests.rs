@@ -14,12 +14,19 @@
 
 use c_str_macro::c_str;
 use serial_test::serial;
+use std::ffi::{CStr, CString};
+use std::panic;
+use std::ptr::{null_mut, NonNull};
 
 use super::*;
-use crate::define_type;
 use crate::mgp::mock_ffi::*;
 use crate::testing::alloc::*;
-use crate::{mock_mgp_once, with_dummy};
+use crate::testing::fake_graph::*;
+use crate::testing::log::{fail_writes, take_lines};
+use crate::value::{MgpValue, Value};
+use crate::{define_nullable_type, define_optional_type, define_procedure, define_type};
+use crate::{mock_fake_graph, mock_mgp_once, mock_mgp_value_doubles, with_dummy};
+use libc::{c_void, free};
 
 #[test]
 #[serial]
@@ -34,6 +41,171 @@ fn test_vertices_iterator() {
     });
 }
 
+#[test]
+#[serial]
+fn test_vertices_iterator_two_vertices() {
+    mock_mgp_once!(
+        mgp_graph_iter_vertices_context,
+        |_, _, iter_ptr_ptr| unsafe {
+            (*iter_ptr_ptr) = alloc_mgp_vertices_iterator();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(
+        mgp_vertices_iterator_get_context,
+        |_, vertex_ptr_ptr| unsafe {
+            (*vertex_ptr_ptr) = alloc_mgp_vertex();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    let mut next_calls = 0;
+    let ctx_next = mgp_vertices_iterator_next_context();
+    ctx_next
+        .expect()
+        .times(2)
+        .returning(move |_, vertex_ptr_ptr| unsafe {
+            next_calls += 1;
+            (*vertex_ptr_ptr) = if next_calls == 1 {
+                alloc_mgp_vertex()
+            } else {
+                std::ptr::null_mut()
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_copy = mgp_vertex_copy_context();
+    ctx_copy
+        .expect()
+        .times(2)
+        .returning(|vertex_ptr, _, vertex_copy_ptr_ptr| unsafe {
+            (*vertex_copy_ptr_ptr) = vertex_ptr;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_destroy = mgp_vertex_destroy_context();
+    ctx_destroy.expect().times(2).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    mock_mgp_once!(mgp_vertices_iterator_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let vertices: Vec<Result<Vertex>> = memgraph.vertices_iter().unwrap().collect();
+        assert_eq!(vertices.len(), 2);
+        assert!(vertices.iter().all(|vertex| vertex.is_ok()));
+    });
+}
+
+#[test]
+#[serial]
+fn test_vertices_iterator_error_mid_stream() {
+    mock_mgp_once!(
+        mgp_graph_iter_vertices_context,
+        |_, _, iter_ptr_ptr| unsafe {
+            (*iter_ptr_ptr) = alloc_mgp_vertices_iterator();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(
+        mgp_vertices_iterator_get_context,
+        |_, vertex_ptr_ptr| unsafe {
+            (*vertex_ptr_ptr) = alloc_mgp_vertex();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_vertex_copy_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+    mock_mgp_once!(mgp_vertices_iterator_next_context, |_, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+    mock_mgp_once!(mgp_vertices_iterator_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut iterator = memgraph.vertices_iter().unwrap();
+        assert_eq!(
+            iterator.next().unwrap().err().unwrap(),
+            Error::UnableToCopyVertex
+        );
+        assert_eq!(
+            iterator.next().unwrap().err().unwrap(),
+            Error::UnableToReturnNextGraphVertex
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_edges_iter() {
+    mock_fake_graph!(FAKE_EDGES);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let edges: Vec<(i64, i64)> = memgraph
+            .edges_iter()
+            .unwrap()
+            .map(|edge| {
+                let edge = edge.unwrap();
+                (
+                    i64::from(edge.from_vertex().unwrap().id()),
+                    i64::from(edge.to_vertex().unwrap().id()),
+                )
+            })
+            .collect();
+        assert_eq!(edges, vec![(1, 2), (1, 3), (2, 3)]);
+    });
+}
+
+#[test]
+#[serial]
+fn test_edges_iter_self_loop() {
+    mock_fake_graph!(FAKE_EDGES_SELF_LOOP);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(memgraph.edges_iter().unwrap().count(), 2);
+    });
+}
+
+#[test]
+#[serial]
+fn test_edges_iter_error_mid_stream() {
+    mock_fake_graph!(FAKE_EDGES, Some(1));
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut edges = memgraph.edges_iter().unwrap();
+        let first = edges.next().unwrap().unwrap();
+        assert_eq!(i64::from(first.to_vertex().unwrap().id()), 2);
+        assert_eq!(
+            edges.next().unwrap().err().unwrap(),
+            Error::UnableToReturnNextEdge
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_approximate_counts() {
+    mock_fake_graph!(FAKE_EDGES);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(memgraph.approximate_vertex_count().unwrap(), 3);
+        assert_eq!(memgraph.approximate_edge_count().unwrap(), 3);
+    });
+}
+
+#[test]
+#[serial]
+fn test_approximate_edge_count_error_mid_stream() {
+    mock_fake_graph!(FAKE_EDGES, Some(1));
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(
+            memgraph.approximate_edge_count().err().unwrap(),
+            Error::UnableToReturnNextEdge
+        );
+    });
+}
+
 #[test]
 #[serial]
 fn test_vertex_by_id() {
@@ -47,6 +219,88 @@ fn test_vertex_by_id() {
     });
 }
 
+#[test]
+#[serial]
+fn test_try_vertex_by_id_found() {
+    mock_mgp_once!(
+        mgp_graph_get_vertex_by_id_context,
+        |_, id, _, vertex_ptr_ptr| unsafe {
+            assert_eq!(id.as_int, 42);
+            (*vertex_ptr_ptr) = alloc_mgp_vertex();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_vertex_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let value = memgraph.try_vertex_by_id(42);
+        assert!(value.unwrap().is_some());
+    });
+}
+
+#[test]
+#[serial]
+fn test_vertex_by_typed_id() {
+    mock_mgp_once!(
+        mgp_graph_get_vertex_by_id_context,
+        |_, id, _, vertex_ptr_ptr| unsafe {
+            assert_eq!(id.as_int, 42);
+            (*vertex_ptr_ptr) = alloc_mgp_vertex();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_vertex_get_id_context, |_, vertex_id_ptr| unsafe {
+        (*vertex_id_ptr).as_int = 42;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_vertex_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let vertex = memgraph.vertex_by_id(Id(42)).unwrap();
+        assert_eq!(vertex.id(), Id(42));
+    });
+}
+
+#[test]
+#[serial]
+fn test_try_vertex_by_id_not_found() {
+    let ctx_get = mgp_graph_get_vertex_by_id_context();
+    ctx_get
+        .expect()
+        .times(2)
+        .returning(|_, _, _, vertex_ptr_ptr| unsafe {
+            (*vertex_ptr_ptr) = std::ptr::null_mut();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph.try_vertex_by_id(42).unwrap().is_none());
+        assert_eq!(
+            memgraph.vertex_by_id(42).err().unwrap(),
+            Error::UnableToFindVertexById
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_try_vertex_by_id_allocation_error() {
+    mock_mgp_once!(mgp_graph_get_vertex_by_id_context, |_, _, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(
+            memgraph.try_vertex_by_id(42).err().unwrap(),
+            Error::UnableToReturnVertexByIdAllocationError
+        );
+    });
+}
+
 #[no_mangle]
 extern "C" fn dummy_c_func(
     _: *mut mgp_list,
@@ -127,3 +381,943 @@ fn test_add_read_procedure() {
             .is_ok());
     });
 }
+
+#[no_mangle]
+extern "C" fn dummy_get_c_func(
+    _: *mut mgp_list,
+    _: *mut mgp_graph,
+    _: *mut mgp_result,
+    _: *mut mgp_memory,
+) {
+}
+
+#[test]
+#[serial]
+fn test_procedure_set() {
+    let mut registered = 0;
+    let ctx_add_read_procedure = mgp_module_add_read_procedure_context();
+    ctx_add_read_procedure
+        .expect()
+        .times(2)
+        .returning(move |_, name, _, proc_ptr_ptr| unsafe {
+            let expected = [c_str!("compute"), c_str!("get")][registered];
+            assert_eq!(CStr::from_ptr(name), expected);
+            registered += 1;
+            (*proc_ptr_ptr) = alloc_mgp_proc();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_int = mgp_type_int_context();
+    ctx_int.expect().times(3).returning(|type_ptr_ptr| unsafe {
+        (*type_ptr_ptr) = alloc_mgp_type();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_add_arg = mgp_proc_add_arg_context();
+    ctx_add_arg
+        .expect()
+        .times(1)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_add_result = mgp_proc_add_result_context();
+    ctx_add_result
+        .expect()
+        .times(2)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let arguments = [define_type!("seed", Type::Int)];
+        let results = [define_type!("node_id", Type::Int)];
+        assert!(ProcedureSet::new()
+            .read_procedure(dummy_c_func, c_str!("compute"), &arguments, &[], &results)
+            .read_procedure(dummy_get_c_func, c_str!("get"), &[], &[], &results)
+            .register(&memgraph)
+            .is_ok());
+
+        let get_memgraph = memgraph.clone().with_procedure(dummy_get_c_func);
+        assert!(get_memgraph.arguments().unwrap().is_empty());
+        assert!(get_memgraph.is_declared_result_field(c_str!("node_id")));
+    });
+}
+
+#[test]
+#[serial]
+fn test_add_write_procedure() {
+    mock_mgp_once!(
+        mgp_module_add_write_procedure_context,
+        |_, name, _, proc_ptr_ptr| unsafe {
+            assert_eq!(CStr::from_ptr(name), c_str!("write_scores"));
+            (*proc_ptr_ptr) = alloc_mgp_proc();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_type_once!(mgp_type_float_context);
+    mock_mgp_once!(mgp_proc_add_result_context, |_, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph
+            .add_write_procedure(
+                dummy_c_func,
+                c_str!("write_scores"),
+                &[],
+                &[],
+                &[define_type!("score", Type::Double)],
+            )
+            .is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_add_write_procedure_error() {
+    mock_mgp_once!(mgp_module_add_write_procedure_context, |_, _, _, _| {
+        mgp_error::MGP_ERROR_INVALID_ARGUMENT
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(
+            memgraph
+                .add_write_procedure(dummy_c_func, c_str!("write_scores"), &[], &[], &[])
+                .err()
+                .unwrap(),
+            Error::UnableToRegisterWriteProcedure
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_check_abort_stops_the_loop() {
+    let mut must_abort_calls = 0;
+    let ctx_must_abort = mgp_must_abort_context();
+    ctx_must_abort.expect().times(5).returning(move |_| {
+        must_abort_calls += 1;
+        if must_abort_calls == 5 {
+            1
+        } else {
+            0
+        }
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut iterations = 0;
+        let result = (|| -> Result<()> {
+            for _ in 0..100 {
+                memgraph.check_abort()?;
+                iterations += 1;
+            }
+            Ok(())
+        })();
+        assert_eq!(result.err().unwrap(), Error::ProcedureAborted);
+        assert_eq!(iterations, 4);
+    });
+}
+
+/// Mocks the creation of records which fails on the given record, each record gets one integer.
+macro_rules! mock_failing_records {
+    ($failing_record:expr) => {
+        let mut created = 0;
+        let ctx_new_record = mgp_result_new_record_context();
+        ctx_new_record
+            .expect()
+            .times($failing_record)
+            .returning(move |_, record_ptr_ptr| unsafe {
+                created += 1;
+                if created == $failing_record {
+                    return mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE;
+                }
+                (*record_ptr_ptr) = NonNull::dangling().as_ptr();
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+        let ctx_make_int = mgp_value_make_int_context();
+        ctx_make_int
+            .expect()
+            .times($failing_record - 1)
+            .returning(|_, _, value_ptr_ptr| unsafe {
+                (*value_ptr_ptr) = alloc_mgp_value();
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+        let ctx_is_double = mgp_value_is_double_context();
+        ctx_is_double
+            .expect()
+            .times($failing_record - 1)
+            .returning(|_, result| unsafe {
+                (*result) = 0;
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+        let ctx_insert = mgp_result_record_insert_context();
+        ctx_insert
+            .expect()
+            .times($failing_record - 1)
+            .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+        let ctx_destroy = mgp_value_destroy_context();
+        ctx_destroy
+            .expect()
+            .times($failing_record - 1)
+            .returning(|ptr| unsafe { free(ptr as *mut c_void) });
+    };
+}
+
+#[test]
+#[serial]
+fn test_emit_records_partial_results() {
+    mock_failing_records!(4);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let emitted = memgraph.emit_records(0..10, true, |record, node_id| {
+            record.insert_int(c_str!("node_id"), node_id)
+        });
+        assert_eq!(emitted.unwrap(), 3);
+        assert_eq!(memgraph.records_emitted(), 3);
+    });
+    assert_eq!(
+        take_lines(),
+        vec!["[WARN] Unable to create result record. Returning 3 partial results."]
+    );
+}
+
+#[test]
+#[serial]
+fn test_emit_records_partial_results_log_error() {
+    mock_failing_records!(4);
+    fail_writes();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let emitted = memgraph.emit_records(0..10, true, |record, node_id| {
+            record.insert_int(c_str!("node_id"), node_id)
+        });
+        assert_eq!(emitted.unwrap(), 3);
+    });
+}
+
+#[test]
+#[serial]
+fn test_emit_records_strict() {
+    mock_failing_records!(4);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let emitted = memgraph.emit_records(0..10, false, |record, node_id| {
+            record.insert_int(c_str!("node_id"), node_id)
+        });
+        assert_eq!(emitted.err().unwrap(), Error::UnableToCreateResultRecord);
+    });
+}
+
+#[test]
+#[serial]
+fn test_result_record_batch() {
+    const ROWS: usize = 1000;
+    mock_mgp_once!(mgp_must_abort_context, |_| 0);
+    let ctx_new_record = mgp_result_new_record_context();
+    ctx_new_record
+        .expect()
+        .times(ROWS)
+        .returning(|_, record_ptr_ptr| unsafe {
+            (*record_ptr_ptr) = NonNull::dangling().as_ptr();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_make_int = mgp_value_make_int_context();
+    ctx_make_int
+        .expect()
+        .times(ROWS)
+        .returning(|_, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_make_double = mgp_value_make_double_context();
+    ctx_make_double
+        .expect()
+        .times(ROWS)
+        .returning(|_, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut is_double_calls = 0;
+    let ctx_is_double = mgp_value_is_double_context();
+    ctx_is_double
+        .expect()
+        .times(2 * ROWS)
+        .returning(move |_, result| unsafe {
+            // Each row inserts an integer followed by a double.
+            is_double_calls += 1;
+            (*result) = (is_double_calls % 2 == 0) as std::os::raw::c_int;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_get_double = mgp_value_get_double_context();
+    ctx_get_double
+        .expect()
+        .times(ROWS)
+        .returning(|_, result| unsafe {
+            (*result) = 0.5;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert
+        .expect()
+        .times(2 * ROWS)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy
+        .expect()
+        .times(2 * ROWS)
+        .returning(|ptr| unsafe { free_mgp(ptr) });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let rows = (0..ROWS).map(|node_id| [Value::Int(node_id as i64), Value::Float(0.5)]);
+        let emitted = memgraph.result_record_batch(&[c_str!("node_id"), c_str!("score")], rows);
+        assert_eq!(emitted.unwrap(), ROWS as u64);
+        assert_eq!(memgraph.records_emitted(), ROWS as u64);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_result_record_batch_non_finite_value() {
+    mock_mgp_once!(mgp_must_abort_context, |_| 0);
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = NonNull::dangling().as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_value_make_double_context,
+        |_, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_value_doubles!(Some(f64::INFINITY));
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert.expect().times(0);
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe { free_mgp(ptr) });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let rows = vec![vec![Value::Float(f64::INFINITY)]];
+        assert_eq!(
+            memgraph
+                .result_record_batch(&[c_str!("score")], rows)
+                .err()
+                .unwrap(),
+            Error::NonFiniteValue {
+                name: "score".to_string()
+            }
+        );
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_result_record_batch_row_length() {
+    mock_mgp_once!(mgp_must_abort_context, |_| 0);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let rows = vec![vec![Value::Int(1)]];
+        assert_eq!(
+            memgraph
+                .result_record_batch(&[c_str!("node_id"), c_str!("score")], rows)
+                .err()
+                .unwrap(),
+            Error::UnexpectedResultRowLength {
+                expected: 2,
+                got: 1
+            }
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_is_mutable() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(!memgraph.is_mutable());
+    });
+}
+
+#[test]
+#[serial]
+fn test_check_mutable() {
+    let mut is_mutable_calls = 0;
+    let ctx_is_mutable = mgp_graph_is_mutable_context();
+    ctx_is_mutable
+        .expect()
+        .times(2)
+        .returning(move |_, result_ptr| unsafe {
+            is_mutable_calls += 1;
+            (*result_ptr) = if is_mutable_calls == 1 { 1 } else { 0 };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph.check_mutable().is_ok());
+        assert_eq!(
+            memgraph.check_mutable().err().unwrap(),
+            Error::ImmutableGraphError
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_create_vertex() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_graph_create_vertex_context,
+        |_, _, vertex_ptr_ptr| unsafe {
+            (*vertex_ptr_ptr) = alloc_mgp_vertex();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_vertex_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph.create_vertex().is_ok());
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_create_vertex_immutable_graph() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(
+            memgraph.create_vertex().err().unwrap(),
+            Error::ImmutableGraphError
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_create_edge() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_graph_create_edge_context,
+        |_, _, _, edge_type, _, edge_ptr_ptr| unsafe {
+            assert_eq!(CStr::from_ptr(edge_type.name), c_str!("SIMILAR"));
+            (*edge_ptr_ptr) = alloc_mgp_edge();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_edge_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let from = Vertex::new(null_mut(), &memgraph);
+        let to = Vertex::new(null_mut(), &memgraph);
+        assert!(memgraph.create_edge(&from, &to, c_str!("SIMILAR")).is_ok());
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_create_edge_immutable_graph() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let from = Vertex::new(null_mut(), &memgraph);
+        let to = Vertex::new(null_mut(), &memgraph);
+        assert_eq!(
+            memgraph
+                .create_edge(&from, &to, c_str!("SIMILAR"))
+                .err()
+                .unwrap(),
+            Error::ImmutableGraphError
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_create_edge_unable_to_allocate() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_graph_create_edge_context, |_, _, _, _, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let from = Vertex::new(null_mut(), &memgraph);
+        let to = Vertex::new(null_mut(), &memgraph);
+        assert_eq!(
+            memgraph
+                .create_edge(&from, &to, c_str!("SIMILAR"))
+                .err()
+                .unwrap(),
+            Error::UnableToCreateEdge
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_create_edge_between_graphs() {
+    with_dummy!(|memgraph: &Memgraph| {
+        let other_memgraph = Memgraph::new(
+            null_mut(),
+            NonNull::dangling().as_ptr(),
+            null_mut(),
+            null_mut(),
+            null_mut(),
+        );
+        let from = Vertex::new(null_mut(), &memgraph);
+        let to = Vertex::new(null_mut(), &other_memgraph);
+        assert_eq!(
+            memgraph
+                .create_edge(&from, &to, c_str!("SIMILAR"))
+                .err()
+                .unwrap(),
+            Error::UnableToCreateEdgeBetweenGraphs
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_delete_vertex() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_graph_delete_vertex_context, |_, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert!(vertex.memgraph().delete_vertex(vertex).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_delete_vertex_with_edges() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_graph_delete_vertex_context, |_, _| {
+        mgp_error::MGP_ERROR_LOGIC_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert_eq!(
+            vertex.memgraph().delete_vertex(vertex).err().unwrap(),
+            Error::UnableToDeleteVertexWithEdges
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_detach_delete_vertex() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_graph_detach_delete_vertex_context, |_, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert!(vertex.memgraph().detach_delete_vertex(vertex).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_delete_edge() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_graph_delete_edge_context, |_, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let edge = Edge::new(null_mut(), &memgraph);
+        assert!(memgraph.delete_edge(&edge).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_delete_immutable_graph() {
+    let ctx_is_mutable = mgp_graph_is_mutable_context();
+    ctx_is_mutable
+        .expect()
+        .times(3)
+        .returning(|_, result_ptr| unsafe {
+            (*result_ptr) = 0;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let vertex = Vertex::new(null_mut(), &memgraph);
+        let edge = Edge::new(null_mut(), &memgraph);
+        assert_eq!(
+            memgraph.delete_vertex(&vertex).err().unwrap(),
+            Error::ImmutableGraphError
+        );
+        assert_eq!(
+            memgraph.detach_delete_vertex(&vertex).err().unwrap(),
+            Error::ImmutableGraphError
+        );
+        assert_eq!(
+            memgraph.delete_edge(&edge).err().unwrap(),
+            Error::ImmutableGraphError
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_is_transactional() {
+    mock_mgp_once!(mgp_graph_is_transactional_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph.is_transactional());
+    });
+}
+
+#[test]
+#[serial]
+fn test_records_emitted() {
+    let ctx_new_record = mgp_result_new_record_context();
+    ctx_new_record
+        .expect()
+        .times(3)
+        .returning(|_, record_ptr_ptr| unsafe {
+            (*record_ptr_ptr) = alloc_mgp_result_record();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(memgraph.records_emitted(), 0);
+        for _ in 0..3 {
+            assert!(memgraph.result_record().is_ok());
+        }
+        assert_eq!(memgraph.records_emitted(), 3);
+        assert_eq!(memgraph.clone().records_emitted(), 3);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(memgraph.records_emitted(), 0);
+    });
+}
+
+#[test]
+#[serial]
+fn test_add_read_procedure_map_and_any_arguments() {
+    mock_mgp_once!(
+        mgp_module_add_read_procedure_context,
+        |_, _, _, proc_ptr_ptr| unsafe {
+            (*proc_ptr_ptr) = alloc_mgp_proc();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_type_once!(mgp_type_map_context);
+    mock_mgp_type_once!(mgp_type_any_context);
+    let mut add_arg_calls = 0;
+    let ctx_add_arg = mgp_proc_add_arg_context();
+    ctx_add_arg
+        .expect()
+        .times(2)
+        .returning(move |_, name, _| unsafe {
+            let expected = if add_arg_calls == 0 {
+                c_str!("config")
+            } else {
+                c_str!("x")
+            };
+            assert_eq!(CStr::from_ptr(name), expected);
+            add_arg_calls += 1;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph
+            .add_read_procedure(
+                dummy_c_func,
+                c_str!("dummy_c_func"),
+                &[
+                    define_type!("config", Type::Map),
+                    define_type!("x", Type::Any),
+                ],
+                &[],
+                &[],
+            )
+            .is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_arguments() {
+    mock_mgp_once!(
+        mgp_module_add_read_procedure_context,
+        |_, _, _, proc_ptr_ptr| unsafe {
+            (*proc_ptr_ptr) = alloc_mgp_proc();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_type_list_context, |_, type_ptr_ptr| unsafe {
+        (*type_ptr_ptr) = alloc_mgp_type();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_type_once!(mgp_type_int_context);
+    mock_mgp_type_once!(mgp_type_float_context);
+    let ctx_add_arg = mgp_proc_add_arg_context();
+    ctx_add_arg
+        .expect()
+        .times(2)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(
+            memgraph.arguments().err().unwrap(),
+            Error::UnableToFindProcedureArguments
+        );
+        assert!(memgraph
+            .add_read_procedure(
+                dummy_c_func,
+                c_str!("dummy_c_func"),
+                &[
+                    define_type!("sources", Type::List, Type::Int),
+                    define_type!("alpha", Type::Double),
+                ],
+                &[],
+                &[],
+            )
+            .is_ok());
+
+        let arguments = memgraph.clone().with_procedure(dummy_c_func).arguments();
+        assert_eq!(
+            arguments.unwrap(),
+            vec![
+                ArgumentType {
+                    name: CString::new("sources").unwrap(),
+                    types: vec![Type::List, Type::Int],
+                    optional: false,
+                    default_value: None,
+                },
+                ArgumentType {
+                    name: CString::new("alpha").unwrap(),
+                    types: vec![Type::Double],
+                    optional: false,
+                    default_value: None,
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_optional_argument_default_value() {
+    mock_mgp_once!(
+        mgp_module_add_read_procedure_context,
+        |_, _, _, proc_ptr_ptr| unsafe {
+            (*proc_ptr_ptr) = alloc_mgp_proc();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_type_once!(mgp_type_int_context);
+    mock_mgp_once!(mgp_value_make_int_context, |_, _, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_proc_add_opt_arg_context, |_, _, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_int_context, |_, int_ptr| unsafe {
+        (*int_ptr) = 1000;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        // The argument wasn't supplied by the caller, so Memgraph applies the default.
+        assert!(memgraph
+            .add_read_procedure(
+                dummy_c_func,
+                c_str!("dummy_c_func"),
+                &[],
+                &[define_optional_type!(
+                    "num_particles",
+                    &MgpValue::make_int(1000, &memgraph).unwrap(),
+                    Type::Int
+                )],
+                &[],
+            )
+            .is_ok());
+
+        let arguments = memgraph
+            .clone()
+            .with_procedure(dummy_c_func)
+            .arguments()
+            .unwrap();
+        assert_eq!(arguments.len(), 1);
+        assert!(arguments[0].optional);
+        assert_eq!(arguments[0].default_value.as_deref(), Some("1000"));
+    });
+}
+
+#[test]
+#[serial]
+fn test_insert_undeclared_result_field() {
+    mock_mgp_once!(
+        mgp_module_add_read_procedure_context,
+        |_, _, _, proc_ptr_ptr| unsafe {
+            (*proc_ptr_ptr) = alloc_mgp_proc();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_type_once!(mgp_type_int_context);
+    mock_mgp_once!(mgp_proc_add_result_context, |_, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_make_int_context, |_, _, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph
+            .add_read_procedure(
+                dummy_c_func,
+                c_str!("dummy_c_func"),
+                &[],
+                &[],
+                &[define_type!("node_id", Type::Int)],
+            )
+            .is_ok());
+
+        // The field is rejected before it's passed to Memgraph. The signature is resolved once,
+        // clearing the registry doesn't affect the handle.
+        let memgraph = memgraph.clone().with_procedure(dummy_c_func);
+        clear_procedure_signatures();
+        let record = memgraph.result_record().unwrap();
+        assert_eq!(
+            record.insert_int(c_str!("nod_id"), 1).err().unwrap(),
+            Error::UnknownResultField {
+                name: "nod_id".to_string()
+            }
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_nullable_result_field() {
+    mock_mgp_once!(
+        mgp_module_add_read_procedure_context,
+        |_, _, _, proc_ptr_ptr| unsafe {
+            (*proc_ptr_ptr) = alloc_mgp_proc();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_type_once!(mgp_type_float_context);
+    mock_mgp_once!(mgp_type_nullable_context, |_, type_ptr_ptr| unsafe {
+        (*type_ptr_ptr) = alloc_mgp_type();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_proc_add_result_context, |_, name, _| unsafe {
+        assert_eq!(CStr::from_ptr(name), c_str!("score"));
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_make_null_context, |_, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_value_doubles!(None);
+    mock_mgp_once!(mgp_result_record_insert_context, |_, name, _| unsafe {
+        assert_eq!(CStr::from_ptr(name), c_str!("score"));
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph
+            .add_read_procedure(
+                dummy_c_func,
+                c_str!("dummy_c_func"),
+                &[],
+                &[],
+                &[define_nullable_type!("score", Type::Double)],
+            )
+            .is_ok());
+        let result = memgraph.result_record().unwrap();
+        assert!(result.insert_null(c_str!("score")).is_ok());
+    });
+}
+
+static QUERY_IDS: Mutex<Vec<(u64, u64)>> = Mutex::new(Vec::new());
+
+define_procedure!(query_id_procedure, |memgraph: &Memgraph| -> Result<()> {
+    let copy = memgraph.clone();
+    QUERY_IDS
+        .lock()
+        .unwrap()
+        .push((memgraph.query_id(), copy.query_id()));
+    Ok(())
+});
+
+#[test]
+#[serial]
+fn test_query_id_stable_within_call() {
+    clear_procedure_signatures();
+    query_id_procedure(null_mut(), null_mut(), null_mut(), null_mut());
+    query_id_procedure(null_mut(), null_mut(), null_mut(), null_mut());
+
+    let ids = QUERY_IDS.lock().unwrap();
+    assert_eq!(ids.len(), 2);
+    assert_eq!(ids[0].0, ids[0].1);
+    assert_eq!(ids[1].0, ids[1].1);
+    assert_ne!(ids[0].0, ids[1].0);
+}
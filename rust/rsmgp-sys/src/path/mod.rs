@@ -113,7 +113,7 @@ impl Path {
         unsafe {
             let mgp_vertex = invoke_mgp_func_with_res!(
                 *mut mgp_vertex,
-                Error::OutOfBoundPathVertexIndex,
+                Error::OutOfBoundPathVertexIndex { index },
                 ffi::mgp_path_vertex_at,
                 self.ptr,
                 index
@@ -126,7 +126,7 @@ impl Path {
         unsafe {
             let mgp_edge = invoke_mgp_func_with_res!(
                 *mut mgp_edge,
-                Error::OutOfBoundPathVertexIndex,
+                Error::OutOfBoundPathEdgeIndex { index },
                 ffi::mgp_path_edge_at,
                 self.ptr,
                 index
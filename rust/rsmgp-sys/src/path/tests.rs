@@ -90,7 +90,10 @@ fn test_vertex_at() {
     });
 
     with_dummy!(Path, |path: &Path| {
-        assert!(path.vertex_at(0).is_err());
+        assert_eq!(
+            path.vertex_at(3).err().unwrap().to_string(),
+            "Out of bound path vertex index 3."
+        );
     });
 }
 
@@ -102,6 +105,9 @@ fn test_edge_at() {
     });
 
     with_dummy!(Path, |path: &Path| {
-        assert!(path.edge_at(0).is_err());
+        assert_eq!(
+            path.edge_at(3).err().unwrap().to_string(),
+            "Out of bound path edge index 3."
+        );
     });
 }
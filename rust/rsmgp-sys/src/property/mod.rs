@@ -17,6 +17,7 @@ use std::ffi::CString;
 
 use crate::memgraph::*;
 use crate::mgp::*;
+use crate::result::*;
 use crate::value::*;
 // Required here, if not present tests linking fails.
 #[double]
@@ -73,9 +74,10 @@ impl Drop for PropertiesIterator {
 }
 
 impl Iterator for PropertiesIterator {
-    type Item = Property;
+    type Item = Result<Property>;
 
-    fn next(&mut self) -> Option<Property> {
+    /// Yields an error if the next property, its name or its value can't be read.
+    fn next(&mut self) -> Option<Result<Property>> {
         unsafe {
             let data = if self.is_first {
                 self.is_first = false;
@@ -84,33 +86,26 @@ impl Iterator for PropertiesIterator {
                     ffi::mgp_properties_iterator_get,
                     self.ptr
                 )
-                .unwrap()
             } else {
                 invoke_mgp_func!(
                     *mut mgp_property,
                     ffi::mgp_properties_iterator_next,
                     self.ptr
                 )
-                .expect("Unable to provide next property. Iteration problem.")
             };
 
-            if data.is_null() {
-                None
-            } else {
-                // Unwrap/panic is I think the only option here because if something fails the
-                // whole procedure should be stopped. Returning empty Option is not an option
-                // because it's not correct. The same applies in case of the property value.
-                let data_ref = data.as_ref().unwrap();
-                Some(Property {
-                    name: match create_cstring(data_ref.name) {
-                        Ok(v) => v,
-                        Err(_) => panic!("Unable to provide next property. Name creation problem."),
-                    },
-                    value: match mgp_raw_value_to_value(data_ref.value, &self.memgraph) {
-                        Ok(v) => v,
-                        Err(_) => panic!("Unable to provide next property. Value create problem."),
-                    },
-                })
+            match data {
+                Err(_) => Some(Err(Error::UnableToReturnNextProperty)),
+                Ok(data) if data.is_null() => None,
+                Ok(data) => {
+                    let data_ref = data.as_ref().unwrap();
+                    Some(create_cstring(data_ref.name).and_then(|name| {
+                        Ok(Property {
+                            name,
+                            value: mgp_raw_value_to_value(data_ref.value, &self.memgraph)?,
+                        })
+                    }))
+                }
             }
         }
     }
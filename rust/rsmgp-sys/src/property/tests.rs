@@ -39,3 +39,21 @@ fn test_properties_iterator() {
         assert!(value_2.is_none());
     });
 }
+
+#[test]
+#[serial]
+fn test_properties_iterator_error() {
+    mock_mgp_once!(mgp_properties_iterator_get_context, |_, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut iterator = PropertiesIterator::new(null_mut(), &memgraph);
+
+        let value = iterator.next();
+        assert!(matches!(
+            value,
+            Some(Err(Error::UnableToReturnNextProperty))
+        ));
+    });
+}
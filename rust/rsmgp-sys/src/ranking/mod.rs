@@ -0,0 +1,84 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Helpers to rank scored nodes before returning them to Memgraph.
+
+use c_str_macro::c_str;
+use std::cmp::Ordering;
+
+use crate::memgraph::*;
+use crate::result::*;
+
+/// Defines how ranks are assigned to nodes with equal scores.
+///
+/// Given the scores `[0.5, 0.3, 0.3, 0.1]`:
+///   * [RankingPolicy::Standard] assigns `[1, 2, 2, 4]` (competition ranking, a gap follows ties).
+///   * [RankingPolicy::Dense] assigns `[1, 2, 2, 3]` (no gaps after ties).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RankingPolicy {
+    Standard,
+    Dense,
+}
+
+/// A node score together with its 1-based rank.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedScore {
+    pub node_id: i64,
+    pub score: f64,
+    pub rank: u64,
+}
+
+/// Sorts the given scores by descending score and assigns 1-based ranks.
+///
+/// Nodes with equal scores share the same rank and are ordered by ascending node id, so the output
+/// is deterministic. Scores are compared with [f64::total_cmp], so NaN scores are ranked above all
+/// other scores and share the same rank.
+pub fn rank_scores(scores: &[(i64, f64)], policy: RankingPolicy) -> Vec<RankedScore> {
+    let mut sorted = scores.to_vec();
+    sorted.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let mut ranked: Vec<RankedScore> = Vec::with_capacity(sorted.len());
+    for (position, (node_id, score)) in sorted.into_iter().enumerate() {
+        let rank = match ranked.last() {
+            Some(previous) if previous.score.total_cmp(&score) == Ordering::Equal => previous.rank,
+            Some(previous) => match policy {
+                RankingPolicy::Standard => position as u64 + 1,
+                RankingPolicy::Dense => previous.rank + 1,
+            },
+            None => 1,
+        };
+        ranked.push(RankedScore {
+            node_id,
+            score,
+            rank,
+        });
+    }
+    ranked
+}
+
+/// Emits one result record per ranked score with `node_id`, `score` and `rank` fields.
+///
+/// The procedure has to register the `node_id` ([crate::rsmgp::Type::Int]), `score`
+/// ([crate::rsmgp::Type::Double]) and `rank` ([crate::rsmgp::Type::Int]) result fields.
+pub fn emit_ranked_scores(memgraph: &Memgraph, scores: &[RankedScore]) -> Result<()> {
+    for ranked in scores {
+        let record = memgraph.result_record()?;
+        record.insert_int(c_str!("node_id"), ranked.node_id)?;
+        record.insert_double(c_str!("score"), ranked.score)?;
+        record.insert_int(c_str!("rank"), ranked.rank as i64)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,98 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serial_test::serial;
+use std::ffi::CStr;
+
+use super::*;
+use crate::mgp::mock_ffi::*;
+use crate::mgp::*;
+use crate::testing::alloc::*;
+use crate::{mock_mgp_once, mock_mgp_value_doubles, with_dummy};
+use libc::{c_void, free};
+
+fn ranks(ranked: &[RankedScore]) -> Vec<(i64, u64)> {
+    ranked.iter().map(|r| (r.node_id, r.rank)).collect()
+}
+
+#[test]
+fn test_rank_scores_standard() {
+    let scores = [(4, 0.1), (3, 0.3), (1, 0.5), (2, 0.3)];
+    let ranked = rank_scores(&scores, RankingPolicy::Standard);
+    assert_eq!(ranks(&ranked), vec![(1, 1), (2, 2), (3, 2), (4, 4)]);
+}
+
+#[test]
+fn test_rank_scores_dense() {
+    let scores = [(4, 0.1), (3, 0.3), (1, 0.5), (2, 0.3)];
+    let ranked = rank_scores(&scores, RankingPolicy::Dense);
+    assert_eq!(ranks(&ranked), vec![(1, 1), (2, 2), (3, 2), (4, 3)]);
+}
+
+#[test]
+fn test_rank_scores_nan() {
+    let scores = [(4, 0.1), (3, f64::NAN), (1, 0.5), (2, f64::NAN), (5, 0.3)];
+    let ranked = rank_scores(&scores, RankingPolicy::Standard);
+    assert_eq!(ranks(&ranked), vec![(2, 1), (3, 1), (1, 3), (5, 4), (4, 5)]);
+    let ranked = rank_scores(&scores, RankingPolicy::Dense);
+    assert_eq!(ranks(&ranked), vec![(2, 1), (3, 1), (1, 2), (5, 3), (4, 4)]);
+}
+
+#[test]
+fn test_rank_scores_empty() {
+    assert!(rank_scores(&[], RankingPolicy::Standard).is_empty());
+}
+
+#[test]
+#[serial]
+fn test_emit_ranked_scores() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_make_int = mgp_value_make_int_context();
+    ctx_make_int
+        .expect()
+        .times(2)
+        .returning(|_, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    mock_mgp_once!(
+        mgp_value_make_double_context,
+        |_, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_value_doubles!(None, Some(0.5), None);
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert
+        .expect()
+        .times(3)
+        .returning(|_, field, _| unsafe {
+            let field = CStr::from_ptr(field).to_str().unwrap();
+            assert!(["node_id", "score", "rank"].contains(&field));
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy.expect().times(3).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let ranked = rank_scores(&[(7, 0.5)], RankingPolicy::Dense);
+        assert!(emit_ranked_scores(&memgraph, &ranked).is_ok());
+    });
+}
@@ -13,9 +13,10 @@
 // limitations under the License.
 //! Simplifies returning results to Memgraph and then to the client.
 
+use c_str_macro::c_str;
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use snafu::Snafu;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 
 use crate::edge::*;
 use crate::list::*;
@@ -30,6 +31,29 @@ use crate::vertex::*;
 use crate::mgp::ffi;
 use mockall_double::double;
 
+/// Distinguishes records of procedures which emit progress records alongside the results.
+///
+/// Such procedures register a `kind` ([crate::rsmgp::Type::String]) result field and a nullable
+/// `progress` ([crate::rsmgp::Type::Double]) result field. All other result fields have to be
+/// nullable because progress records don't set them. Note that Memgraph delivers the records to
+/// the client once the procedure finishes, the `kind` field only allows the client to tell them
+/// apart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordKind {
+    Progress,
+    Result,
+}
+
+impl RecordKind {
+    /// Value stored in the `kind` result field.
+    pub fn name(&self) -> &'static CStr {
+        match self {
+            RecordKind::Progress => c_str!("progress"),
+            RecordKind::Result => c_str!("result"),
+        }
+    }
+}
+
 pub struct ResultRecord {
     ptr: *mut mgp_result_record,
     memgraph: Memgraph,
@@ -44,6 +68,7 @@ impl ResultRecord {
                 ffi::mgp_result_new_record,
                 memgraph.result_ptr()
             )?;
+            memgraph.count_emitted_record();
             Ok(ResultRecord {
                 ptr: mgp_ptr,
                 memgraph: memgraph.clone(),
@@ -51,16 +76,62 @@ impl ResultRecord {
         }
     }
 
+    /// Creates a new record tagged with the given [RecordKind].
+    ///
+    /// Result records get the `progress` field set to null, progress records get the given
+    /// `progress` value and null for each of the `result_fields`.
+    pub fn create_with_kind(
+        memgraph: &Memgraph,
+        kind: RecordKind,
+        progress: f64,
+        result_fields: &[&CStr],
+    ) -> Result<ResultRecord> {
+        let record = ResultRecord::create(memgraph)?;
+        record.insert_string(c_str!("kind"), kind.name())?;
+        match kind {
+            RecordKind::Progress => {
+                record.insert_double(c_str!("progress"), progress)?;
+                for field in result_fields {
+                    record.insert_null(field)?;
+                }
+            }
+            RecordKind::Result => record.insert_null(c_str!("progress"))?,
+        }
+        Ok(record)
+    }
+
+    /// Fails with [Error::UnknownResultField] if the field isn't declared as a result field of
+    /// the procedure, e.g., because of a typo in the field name.
+    ///
+    /// Fails with [Error::NonFiniteValue] if the value is a NaN or infinite double, e.g., a score
+    /// divided by zero, instead of passing such value to the client.
     pub fn insert_mgp_value(&self, field: &CStr, value: &MgpValue) -> Result<()> {
+        let unknown_field = || Error::UnknownResultField {
+            name: field.to_string_lossy().into_owned(),
+        };
+        if !self.memgraph.is_declared_result_field(field) {
+            return Err(unknown_field());
+        }
         unsafe {
-            invoke_void_mgp_func_with_res!(
-                Error::UnableToInsertResultValue,
+            if value.is_double() {
+                let double = invoke_mgp_func!(f64, ffi::mgp_value_get_double, value.mgp_ptr())
+                    .map_err(|_| Error::UnableToInsertResultValue)?;
+                if !double.is_finite() {
+                    return Err(Error::NonFiniteValue {
+                        name: field.to_string_lossy().into_owned(),
+                    });
+                }
+            }
+            match invoke_void_mgp_func!(
                 ffi::mgp_result_record_insert,
                 self.ptr,
                 field.as_ptr(),
                 value.mgp_ptr()
-            )?;
-            Ok(())
+            ) {
+                Ok(()) => Ok(()),
+                Err(MgpError::OutOfRange) => Err(unknown_field()),
+                Err(_) => Err(Error::UnableToInsertResultValue),
+            }
         }
     }
 
@@ -76,6 +147,8 @@ impl ResultRecord {
         self.insert_mgp_value(field, &MgpValue::make_int(value, &self.memgraph)?)
     }
 
+    /// Fails with [Error::NonFiniteValue] if the value is NaN or infinite, see
+    /// [ResultRecord::insert_mgp_value].
     pub fn insert_double(&self, field: &CStr, value: f64) -> Result<()> {
         self.insert_mgp_value(field, &MgpValue::make_double(value, &self.memgraph)?)
     }
@@ -84,6 +157,13 @@ impl ResultRecord {
         self.insert_mgp_value(field, &MgpValue::make_string(value, &self.memgraph)?)
     }
 
+    /// Same as [ResultRecord::insert_string] for Rust strings. Fails with
+    /// [Error::UnableToCreateCString] if the value contains a nul byte.
+    pub fn insert_str(&self, field: &CStr, value: &str) -> Result<()> {
+        let value = CString::new(value).map_err(|_| Error::UnableToCreateCString)?;
+        self.insert_string(field, &value)
+    }
+
     pub fn insert_list(&self, field: &CStr, value: &List) -> Result<()> {
         self.insert_mgp_value(field, &MgpValue::make_list(value, &self.memgraph)?)
     }
@@ -124,6 +204,70 @@ impl ResultRecord {
     }
 }
 
+/// Collects the values of a single result record and passes them to Memgraph only on
+/// [RecordBuilder::finish].
+///
+/// The builder takes the declared result fields from the signature of the executing procedure,
+/// inserting an undeclared field or finishing the record without all of them is an error. Since
+/// nothing is passed to Memgraph before `finish`, builders could also be collected and sorted
+/// before the records are emitted.
+pub struct RecordBuilder {
+    fields: Vec<CString>,
+    values: Vec<Option<Value>>,
+    memgraph: Memgraph,
+}
+
+impl RecordBuilder {
+    /// Fails with [Error::UnableToFindProcedureResultFields] if the procedure wasn't registered
+    /// with [Memgraph::add_read_procedure] or [Memgraph::add_write_procedure].
+    pub fn new(memgraph: &Memgraph) -> Result<RecordBuilder> {
+        let fields = memgraph.result_fields()?;
+        Ok(RecordBuilder {
+            values: fields.iter().map(|_| None).collect(),
+            fields,
+            memgraph: memgraph.clone(),
+        })
+    }
+
+    pub fn insert(&mut self, field: &CStr, value: Value) -> Result<&mut RecordBuilder> {
+        match self
+            .fields
+            .iter()
+            .position(|declared| declared.as_c_str() == field)
+        {
+            Some(index) => {
+                self.values[index] = Some(value);
+                Ok(self)
+            }
+            None => Err(Error::UnknownResultField {
+                name: field.to_string_lossy().into_owned(),
+            }),
+        }
+    }
+
+    /// Returns the value inserted under the given field, e.g., to sort the builders.
+    pub fn value(&self, field: &CStr) -> Option<&Value> {
+        let index = self
+            .fields
+            .iter()
+            .position(|declared| declared.as_c_str() == field)?;
+        self.values[index].as_ref()
+    }
+
+    /// Creates the result record and inserts all collected values.
+    pub fn finish(self) -> Result<()> {
+        if self.values.iter().any(|value| value.is_none()) {
+            return Err(Error::UnableToFinishResultRecordMissingField);
+        }
+        let record = ResultRecord::create(&self.memgraph)?;
+        for (field, value) in self.fields.iter().zip(self.values.iter()) {
+            let value = value.as_ref().unwrap();
+            record.insert_mgp_value(field, &value.to_mgp_value(&self.memgraph)?)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, PartialEq, Snafu)]
 #[snafu(visibility = "pub")]
 pub enum Error {
@@ -135,25 +279,43 @@ pub enum Error {
     #[snafu(display("Unable to create duration from chrono::Duration."))]
     UnableToCreateDurationFromChronoDuration,
 
+    #[snafu(display("Unable to create duration because it overflows the microseconds range."))]
+    DurationOverflow,
+
     // EDGE
     #[snafu(display("Unable to copy edge."))]
     UnableToCopyEdge,
 
-    #[snafu(display("Unable to return edge property because of value allocation error."))]
-    UnableToReturnEdgePropertyValueAllocationError,
+    #[snafu(display("Unable to return next edge during edges iteration."))]
+    UnableToReturnNextEdge,
 
-    #[snafu(display("Unable to return edge property because of value creation error."))]
-    UnableToReturnEdgePropertyValueCreationError,
+    #[snafu(display(
+        "Unable to return edge property {} because of value allocation error.",
+        name
+    ))]
+    UnableToReturnEdgePropertyValueAllocationError { name: String },
 
-    #[snafu(display("Unable to return edge property because of name allocation error."))]
-    UnableToReturnEdgePropertyNameAllocationError,
+    #[snafu(display(
+        "Unable to return edge property {} because of value creation error.",
+        name
+    ))]
+    UnableToReturnEdgePropertyValueCreationError { name: String },
 
-    #[snafu(display("Unable to return edge property because the edge is deleted."))]
-    UnableToReturnEdgePropertyDeletedObjectError,
+    #[snafu(display(
+        "Unable to return edge property {} because of name allocation error.",
+        name
+    ))]
+    UnableToReturnEdgePropertyNameAllocationError { name: String },
+
+    #[snafu(display("Unable to return edge property {} because the edge is deleted.", name))]
+    UnableToReturnEdgePropertyDeletedObjectError { name: String },
 
     #[snafu(display("Unable to return edge properties iterator."))]
     UnableToReturnEdgePropertiesIterator,
 
+    #[snafu(display("Edge weight property has to be a number."))]
+    NonNumericEdgeWeight,
+
     // LIST
     #[snafu(display("Unable to create empty list."))]
     UnableToCreateEmptyList,
@@ -170,6 +332,21 @@ pub enum Error {
     #[snafu(display("Unable to access list value by index."))]
     UnableToAccessListValueByIndex,
 
+    #[snafu(display("Unable to read the list value at index {}.", index))]
+    UnableToReadListValue { index: u64 },
+
+    #[snafu(display(
+        "Unexpected type of the list value at index {}, expected {}, got {}.",
+        index,
+        expected,
+        got
+    ))]
+    UnexpectedListValueType {
+        index: u64,
+        expected: &'static str,
+        got: &'static str,
+    },
+
     // LOCALTIME
     #[snafu(display("Unable to create local time from NaiveTime."))]
     UnableToCreateLocalTimeFromNaiveTime,
@@ -178,6 +355,18 @@ pub enum Error {
     #[snafu(display("Unable to create local date time from NaiveDateTime."))]
     UnableToCreateLocalDateTimeFromNaiveDateTime,
 
+    #[snafu(display(
+        "Unable to create local date time because year {} is out of the range {}..={}.",
+        year,
+        min,
+        max
+    ))]
+    LocalDateTimeYearOutOfRange { year: i32, min: i32, max: i32 },
+
+    // LOG
+    #[snafu(display("Unable to write the log message."))]
+    UnableToWriteLog,
+
     // MAP
     #[snafu(display("Unable to copy map."))]
     UnableToCopyMap,
@@ -195,15 +384,54 @@ pub enum Error {
     UnableToCreateMapIterator,
 
     // MEMGRAPH
+    #[snafu(display("Unable to modify the graph because the procedure is read-only."))]
+    ImmutableGraphError,
+
     #[snafu(display("Unable to create graph vertices iterator."))]
     UnableToCreateGraphVerticesIterator,
 
+    #[snafu(display("Unable to return next graph vertex during vertices iteration."))]
+    UnableToReturnNextGraphVertex,
+
     #[snafu(display("Unable to find vertex by id."))]
     UnableToFindVertexById,
 
+    #[snafu(display("Unable to create vertex."))]
+    UnableToCreateVertex,
+
+    #[snafu(display("Unable to create edge."))]
+    UnableToCreateEdge,
+
+    #[snafu(display("Unable to create edge because a vertex belongs to another graph."))]
+    UnableToCreateEdgeBetweenGraphs,
+
+    #[snafu(display("Unable to delete vertex."))]
+    UnableToDeleteVertex,
+
+    #[snafu(display("Unable to delete vertex because it still has edges."))]
+    UnableToDeleteVertexWithEdges,
+
+    #[snafu(display("Unable to delete edge."))]
+    UnableToDeleteEdge,
+
+    #[snafu(display("Unable to return vertex by id because of allocation error."))]
+    UnableToReturnVertexByIdAllocationError,
+
+    #[snafu(display("Procedure execution was aborted by Memgraph."))]
+    ProcedureAborted,
+
+    #[snafu(display("Unable to find the arguments of the executing procedure."))]
+    UnableToFindProcedureArguments,
+
+    #[snafu(display("Unable to find the result fields of the executing procedure."))]
+    UnableToFindProcedureResultFields,
+
     #[snafu(display("Unable to register read procedure."))]
     UnableToRegisterReadProcedure,
 
+    #[snafu(display("Unable to register write procedure."))]
+    UnableToRegisterWriteProcedure,
+
     #[snafu(display("Unable to add required arguments."))]
     UnableToAddRequiredArguments,
 
@@ -216,15 +444,19 @@ pub enum Error {
     #[snafu(display("Unable to add deprecated return type."))]
     UnableToAddDeprecatedReturnType,
 
+    // MODULE
+    #[snafu(display("{}", message))]
+    ModuleError { message: String },
+
     // PATH
     #[snafu(display("Unable to copy path."))]
     UnableToCopyPath,
 
-    #[snafu(display("Out of bound path vertex index."))]
-    OutOfBoundPathVertexIndex,
+    #[snafu(display("Out of bound path vertex index {}.", index))]
+    OutOfBoundPathVertexIndex { index: u64 },
 
-    #[snafu(display("Out of bound path edge index."))]
-    OutOfBoundPathEdgeIndex,
+    #[snafu(display("Out of bound path edge index {}.", index))]
+    OutOfBoundPathEdgeIndex { index: u64 },
 
     #[snafu(display("Unable to create path with start Vertex."))]
     UnableToCreatePathWithStartVertex,
@@ -234,13 +466,33 @@ pub enum Error {
     ))]
     UnableToExpandPath,
 
+    // PROPERTY
+    #[snafu(display("Unable to return next property."))]
+    UnableToReturnNextProperty,
+
     // RESULT
     #[snafu(display("Unable to create result record."))]
     UnableToCreateResultRecord,
 
+    #[snafu(display("Unknown result field {}, it isn't declared by the procedure.", name))]
+    UnknownResultField { name: String },
+
+    #[snafu(display("Unable to insert non-finite value into result field {}.", name))]
+    NonFiniteValue { name: String },
+
     #[snafu(display("Unable to insert result record."))]
     UnableToInsertResultValue,
 
+    #[snafu(display(
+        "Unable to insert result row with {} values into {} result fields.",
+        got,
+        expected
+    ))]
+    UnexpectedResultRowLength { expected: usize, got: usize },
+
+    #[snafu(display("Unable to finish result record because a declared field is missing."))]
+    UnableToFinishResultRecordMissingField,
+
     // VALUE
     #[snafu(display("Unable to create new CString."))]
     UnableToCreateCString,
@@ -294,14 +546,23 @@ pub enum Error {
     #[snafu(display("Unable to copy vertex."))]
     UnableToCopyVertex,
 
-    #[snafu(display("Out of bound label index."))]
-    OutOfBoundLabelIndexError,
+    #[snafu(display("Out of bound label index {}, the vertex has {} labels.", index, count))]
+    OutOfBoundLabelIndexError { index: u64, count: u64 },
 
-    #[snafu(display("Unable to get vertex property."))]
-    UnableToGetVertexProperty,
+    #[snafu(display("Unable to get vertex property {}.", name))]
+    UnableToGetVertexProperty { name: String },
 
-    #[snafu(display("Unable to return vertex property because of make name error."))]
-    UnableToReturnVertexPropertyMakeNameEror,
+    #[snafu(display(
+        "Unable to return vertex property {} because of value creation error.",
+        name
+    ))]
+    UnableToReturnVertexPropertyValueCreationError { name: String },
+
+    #[snafu(display(
+        "Unable to return vertex property {} because of make name error.",
+        name
+    ))]
+    UnableToReturnVertexPropertyMakeNameEror { name: String },
 
     #[snafu(display("Unable to return vertex properties iterator."))]
     UnableToReturnVertexPropertiesIterator,
@@ -320,6 +581,12 @@ pub enum Error {
 
     #[snafu(display("Unable to check if vertex has a label."))]
     UnableToCheckVertexHasLabel,
+
+    #[snafu(display("Unable to set vertex property {}.", name))]
+    UnableToSetVertexProperty { name: String },
+
+    #[snafu(display("Unable to add vertex label."))]
+    UnableToAddVertexLabel,
 }
 
 /// A result type holding [Error] by default.
@@ -14,13 +14,14 @@
 
 use c_str_macro::c_str;
 use serial_test::serial;
-use std::ptr::null_mut;
+use std::ffi::CStr;
+use std::ptr::{null_mut, NonNull};
 
 use super::*;
 use crate::memgraph::Memgraph;
 use crate::mgp::mock_ffi::*;
 use crate::testing::alloc::*;
-use crate::{mock_mgp_once, with_dummy};
+use crate::{mock_mgp_once, mock_mgp_value_doubles, with_dummy};
 use libc::{c_void, free};
 
 #[test]
@@ -165,6 +166,23 @@ fn test_insert_value() {
         free(ptr as *mut c_void);
     });
 
+    mock_mgp_value_doubles!(
+        None,
+        None,
+        None,
+        Some(0.1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
     with_dummy!(|memgraph: &Memgraph| {
         let result_record = ResultRecord::create(&memgraph).unwrap();
         assert_eq!(
@@ -271,3 +289,438 @@ fn test_insert_value() {
         );
     });
 }
+
+#[test]
+#[serial]
+fn test_insert_unknown_field() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_value_make_with_mem!(mgp_value_make_int_context);
+    mock_mgp_once!(mgp_result_record_insert_context, |_, _, _| {
+        mgp_error::MGP_ERROR_OUT_OF_RANGE
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    mock_mgp_value_doubles!(None);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let record = memgraph.result_record().unwrap();
+        assert_eq!(
+            record.insert_int(c_str!("nod_id"), 1).err().unwrap(),
+            Error::UnknownResultField {
+                name: "nod_id".to_string()
+            }
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_insert_non_finite_double() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_make_double = mgp_value_make_double_context();
+    ctx_make_double
+        .expect()
+        .times(3)
+        .returning(|_, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    mock_mgp_value_doubles!(Some(f64::NAN), Some(f64::INFINITY), Some(0.25));
+    mock_mgp_once!(mgp_result_record_insert_context, |_, _, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy
+        .expect()
+        .times(3)
+        .returning(|ptr| unsafe { free_mgp(ptr) });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let record = memgraph.result_record().unwrap();
+        let non_finite_error = Error::NonFiniteValue {
+            name: "score".to_string(),
+        };
+        assert_eq!(
+            record
+                .insert_double(c_str!("score"), f64::NAN)
+                .err()
+                .unwrap(),
+            non_finite_error
+        );
+        assert_eq!(
+            record
+                .insert_double(c_str!("score"), f64::INFINITY)
+                .err()
+                .unwrap(),
+            non_finite_error
+        );
+        assert!(record.insert_double(c_str!("score"), 0.25).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_record_builder_non_finite_value() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_value_make_with_mem!(mgp_value_make_double_context);
+    mock_mgp_value_doubles!(Some(f64::NAN));
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert.expect().times(0);
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe { free_mgp(ptr) });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let memgraph = memgraph.clone().with_result_fields(&[c_str!("score")]);
+        let mut builder = RecordBuilder::new(&memgraph).unwrap();
+        builder
+            .insert(c_str!("score"), Value::Float(f64::NAN))
+            .unwrap();
+        assert_eq!(
+            builder.finish().err().unwrap(),
+            Error::NonFiniteValue {
+                name: "score".to_string()
+            }
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_insert_frees_values() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = NonNull::dangling().as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_value_make_with_mem!(mgp_value_make_int_context);
+    mock_mgp_value_make_with_mem!(mgp_value_make_double_context);
+    mock_mgp_value_make_with_mem!(mgp_value_make_string_context);
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert
+        .expect()
+        .times(3)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    // Memgraph copies the inserted values, the temporary ones are owned by the wrapper.
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy
+        .expect()
+        .times(3)
+        .returning(|ptr| unsafe { free_mgp(ptr) });
+
+    mock_mgp_value_doubles!(None, Some(0.25), None);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let record = memgraph.result_record().unwrap();
+        record.insert_int(c_str!("node_id"), 1).unwrap();
+        record.insert_double(c_str!("score"), 0.25).unwrap();
+        record.insert_string(c_str!("label"), c_str!("x")).unwrap();
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_insert_str() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = NonNull::dangling().as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let mut made = 0;
+    let ctx_make_string = mgp_value_make_string_context();
+    ctx_make_string
+        .expect()
+        .times(2)
+        .returning(move |value, _, value_ptr_ptr| unsafe {
+            let expected = ["green", "zöld 🟢"][made];
+            assert_eq!(CStr::from_ptr(value).to_str().unwrap(), expected);
+            made += 1;
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert
+        .expect()
+        .times(2)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy
+        .expect()
+        .times(2)
+        .returning(|ptr| unsafe { free_mgp(ptr) });
+
+    mock_mgp_value_doubles!(None, None);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let record = memgraph.result_record().unwrap();
+        assert!(record.insert_str(c_str!("color"), "green").is_ok());
+        assert!(record.insert_str(c_str!("color"), "zöld 🟢").is_ok());
+        assert_eq!(
+            record.insert_str(c_str!("color"), "gr\0een").err().unwrap(),
+            Error::UnableToCreateCString
+        );
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_emit_progress() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_value_make_string_context,
+        |value, _, value_ptr_ptr| unsafe {
+            assert_eq!(CStr::from_ptr(value), c_str!("progress"));
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(
+        mgp_value_make_double_context,
+        |value, _, value_ptr_ptr| unsafe {
+            assert_eq!(value, 0.5);
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_value_make_null_context, |_, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert
+        .expect()
+        .times(3)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy.expect().times(3).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    mock_mgp_value_doubles!(None, Some(0.5), None);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph.emit_progress(0.5, &[c_str!("score")]).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_tagged_result_record() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_value_make_string_context,
+        |value, _, value_ptr_ptr| unsafe {
+            assert_eq!(CStr::from_ptr(value), c_str!("result"));
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_value_make_null_context, |_, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert
+        .expect()
+        .times(2)
+        .returning(|_, field, _| unsafe {
+            let field = CStr::from_ptr(field);
+            assert!(field == c_str!("kind") || field == c_str!("progress"));
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy.expect().times(2).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    mock_mgp_value_doubles!(None, None);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        assert!(memgraph.tagged_result_record().is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_insert_feature_map() {
+    // Columns depending on the procedure arguments are returned as a single map field.
+    let ctx_make_map = mgp_map_make_empty_context();
+    ctx_make_map
+        .expect()
+        .times(2)
+        .returning(|_, map_ptr_ptr| unsafe {
+            (*map_ptr_ptr) = alloc_mgp_map();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_make_double = mgp_value_make_double_context();
+    ctx_make_double
+        .expect()
+        .times(2)
+        .returning(|_, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_map_insert = mgp_map_insert_context();
+    ctx_map_insert
+        .expect()
+        .times(2)
+        .returning(|_, key, _| unsafe {
+            let key = CStr::from_ptr(key);
+            assert!(key == c_str!("degree") || key == c_str!("pagerank"));
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_map_iter_items_context, |_, _, iter_ptr_ptr| unsafe {
+        (*iter_ptr_ptr) = alloc_mgp_map_items_iterator();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(
+        mgp_map_items_iterator_get_context,
+        |_, item_ptr_ptr| unsafe {
+            (*item_ptr_ptr) = null_mut();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_map_items_iterator_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    mock_mgp_value_make_without_mem!(mgp_value_make_map_context);
+    mock_mgp_once!(mgp_result_record_insert_context, |_, field, _| unsafe {
+        assert_eq!(CStr::from_ptr(field), c_str!("features"));
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy.expect().times(3).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+    mock_mgp_once!(mgp_map_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    mock_mgp_value_doubles!(None);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let features = [(c_str!("degree"), 3.0), (c_str!("pagerank"), 0.25)];
+        let map = Map::make_empty(&memgraph).unwrap();
+        for (name, value) in features.iter() {
+            map.insert(name, &Value::Float(*value)).unwrap();
+        }
+        let result_record = ResultRecord::create(&memgraph).unwrap();
+        assert!(result_record.insert_map(c_str!("features"), &map).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_record_builder() {
+    mock_mgp_once!(mgp_result_new_record_context, |_, record_ptr_ptr| unsafe {
+        (*record_ptr_ptr) = alloc_mgp_result_record();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_value_make_with_mem!(mgp_value_make_int_context);
+    mock_mgp_value_make_with_mem!(mgp_value_make_double_context);
+    let mut insert_calls = 0;
+    let ctx_insert = mgp_result_record_insert_context();
+    ctx_insert
+        .expect()
+        .times(2)
+        .returning(move |_, field, _| unsafe {
+            insert_calls += 1;
+            // Fields are inserted in the declared order.
+            let expected = if insert_calls == 1 {
+                c_str!("node_id")
+            } else {
+                c_str!("score")
+            };
+            assert_eq!(CStr::from_ptr(field), expected);
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_destroy = mgp_value_destroy_context();
+    ctx_destroy.expect().times(2).returning(|ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    mock_mgp_value_doubles!(None, Some(0.5));
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let memgraph = memgraph
+            .clone()
+            .with_result_fields(&[c_str!("node_id"), c_str!("score")]);
+        let mut builder = RecordBuilder::new(&memgraph).unwrap();
+        builder
+            .insert(c_str!("score"), Value::Float(0.5))
+            .unwrap()
+            .insert(c_str!("node_id"), Value::Int(1))
+            .unwrap();
+        assert!(matches!(
+            builder.value(c_str!("score")),
+            Some(Value::Float(_))
+        ));
+        assert!(builder.finish().is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_record_builder_unknown_field() {
+    with_dummy!(|memgraph: &Memgraph| {
+        let memgraph = memgraph
+            .clone()
+            .with_result_fields(&[c_str!("node_id"), c_str!("score")]);
+        let mut builder = RecordBuilder::new(&memgraph).unwrap();
+        assert_eq!(
+            builder.insert(c_str!("rank"), Value::Int(1)).err().unwrap(),
+            Error::UnknownResultField {
+                name: "rank".to_string()
+            }
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_record_builder_missing_field() {
+    let ctx_new_record = mgp_result_new_record_context();
+    ctx_new_record.expect().times(0);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let memgraph = memgraph
+            .clone()
+            .with_result_fields(&[c_str!("node_id"), c_str!("score")]);
+        let mut builder = RecordBuilder::new(&memgraph).unwrap();
+        builder.insert(c_str!("node_id"), Value::Int(1)).unwrap();
+        assert_eq!(
+            builder.finish().err().unwrap(),
+            Error::UnableToFinishResultRecordMissingField
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_record_builder_unknown_procedure() {
+    with_dummy!(|memgraph: &Memgraph| {
+        assert_eq!(
+            RecordBuilder::new(&memgraph).err().unwrap(),
+            Error::UnableToFindProcedureResultFields
+        );
+    });
+}
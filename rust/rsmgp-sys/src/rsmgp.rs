@@ -25,7 +25,8 @@
 //! }
 //! ```
 
-use std::ffi::CStr;
+use std::any::Any;
+use std::ffi::{CStr, CString};
 
 use crate::memgraph::*;
 #[double]
@@ -49,6 +50,10 @@ use mockall_double::double;
 ///     // Implementation
 /// }
 /// ```
+///
+/// The error returned by the function is passed to Memgraph by using its `Display`
+/// implementation. Module specific error types could be mixed with [crate::result::Error] by using
+/// [define_module_error].
 #[macro_export]
 macro_rules! define_procedure {
     ($c_name:ident, $rs_func:expr) => {
@@ -59,20 +64,23 @@ macro_rules! define_procedure {
             result: *mut mgp_result,
             memory: *mut mgp_memory,
         ) {
+            let query_id = $crate::memgraph::next_query_id();
             let prev_hook = panic::take_hook();
             panic::set_hook(Box::new(|_| { /* Do nothing. */ }));
 
             let procedure_result = panic::catch_unwind(|| {
-                let memgraph = Memgraph::new(args, graph, result, memory, std::ptr::null_mut());
-                match $rs_func(&memgraph) {
-                    Ok(_) => (),
-                    Err(e) => {
-                        let msg = e.to_string();
-                        println!("{}", msg);
-                        let c_msg =
-                            CString::new(msg).expect("Unable to create Memgraph error message!");
-                        set_memgraph_error_msg(&c_msg, &memgraph);
-                    }
+                let memgraph = Memgraph::new(args, graph, result, memory, std::ptr::null_mut())
+                    .with_procedure($c_name)
+                    .with_query_id(query_id);
+                let error_msg = match $rs_func(&memgraph) {
+                    Ok(_) => None,
+                    Err(e) => Some(e.to_string()),
+                };
+                if let Some(msg) = error_msg {
+                    println!("{}", msg);
+                    let c_msg =
+                        CString::new(msg).expect("Unable to create Memgraph error message!");
+                    set_memgraph_error_msg(&c_msg, &memgraph);
                 }
             });
 
@@ -81,18 +89,45 @@ macro_rules! define_procedure {
                 Ok(_) => {}
                 Err(e) => {
                     println!("Procedure panic!");
-                    let memgraph = Memgraph::new(args, graph, result, memory, std::ptr::null_mut());
-                    match e.downcast::<&str>() {
-                        Ok(msg) => {
-                            println!("{}", msg);
-                            let c_msg = CString::new(msg.as_bytes())
-                                .expect("Unable to create Memgraph PANIC error message!");
-                            set_memgraph_error_msg(&c_msg, &memgraph);
-                        }
-                        Err(_) => {
-                            println!("Unknown type of panic!.");
-                        }
-                    }
+                    let memgraph = Memgraph::new(args, graph, result, memory, std::ptr::null_mut())
+                        .with_procedure($c_name)
+                        .with_query_id(query_id);
+                    let c_msg = $crate::rsmgp::panic_message(&*e);
+                    println!("{}", c_msg.to_string_lossy());
+                    set_memgraph_error_msg(&c_msg, &memgraph);
+                }
+            }
+        }
+    };
+}
+
+/// Implements the conversion from the module error type into [crate::result::Error::ModuleError].
+///
+/// After the conversion is defined, module errors could be propagated with `?` inside the
+/// [define_procedure] functions returning [Result]. Memgraph receives the `Display` message of the
+/// module error.
+///
+/// Example
+///
+/// ```no run
+/// #[derive(Debug)]
+/// enum ModuleError {
+///     InvalidArgument,
+/// }
+///
+/// impl std::fmt::Display for ModuleError {
+///     // Implementation
+/// }
+///
+/// define_module_error!(ModuleError);
+/// ```
+#[macro_export]
+macro_rules! define_module_error {
+    ($error_type:ty) => {
+        impl From<$error_type> for $crate::result::Error {
+            fn from(error: $error_type) -> Self {
+                $crate::result::Error::ModuleError {
+                    message: error.to_string(),
                 }
             }
         }
@@ -169,6 +204,7 @@ macro_rules! close_module {
 
 /// Used to pass expected types (arguments, optional arguments, result field types) to the Memgraph
 /// engine.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Type {
     Any,
     Bool,
@@ -177,8 +213,11 @@ pub enum Type {
     Double,
     String,
     Map,
+    /// Cypher `Node`, read as [crate::value::Value::Vertex].
     Vertex,
+    /// Cypher `Relationship`, read as [crate::value::Value::Edge].
     Edge,
+    /// Cypher `Path`, read as [crate::value::Value::Path].
     Path,
     Nullable,
     List,
@@ -231,6 +270,25 @@ macro_rules! define_type {
     };
 }
 
+/// Defines a nullable type, e.g., a result field that doesn't always have a value. Such fields
+/// can be set with [crate::result::ResultRecord::insert_null] instead of a sentinel value.
+///
+/// Example of defining a nullable double
+///
+/// ```no run
+/// define_nullable_type!("score", Type::Double);
+/// ```
+#[macro_export]
+macro_rules! define_nullable_type {
+    ($name:literal, $($types:expr),+) => {
+        NamedType {
+            name: &c_str!($name),
+            types: &[Type::Nullable, $($types),+],
+            deprecated: false,
+        }
+    };
+}
+
 /// Defines a deprecated type.
 ///
 /// Example of defining a list of integers
@@ -267,6 +325,20 @@ macro_rules! define_optional_type {
     };
 }
 
+/// Creates the error message passed to Memgraph when a procedure panics. The panic payload is
+/// included if it's a string (e.g., `panic!("...")` or `.expect("...")`).
+pub fn panic_message(payload: &(dyn Any + Send)) -> CString {
+    let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+        format!("Procedure panicked: {}", msg)
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        format!("Procedure panicked: {}", msg)
+    } else {
+        "Procedure panicked.".to_string()
+    };
+    // Interior nul bytes would make the message invalid, they are dropped.
+    CString::new(msg.replace('\0', "")).unwrap()
+}
+
 /// Sets error that will be returned to the caller.
 pub fn set_memgraph_error_msg(msg: &CStr, memgraph: &Memgraph) {
     unsafe {
@@ -283,9 +355,13 @@ pub fn set_memgraph_error_msg(msg: &CStr, memgraph: &Memgraph) {
 mod tests {
     use c_str_macro::c_str;
     use serial_test::serial;
+    use std::panic;
+    use std::ptr::null_mut;
 
     use super::*;
     use crate::mgp::mock_ffi::*;
+    use crate::mgp::*;
+    use crate::result::{Error, Result};
     use crate::{mock_mgp_once, with_dummy};
 
     #[test]
@@ -299,4 +375,87 @@ mod tests {
             set_memgraph_error_msg(c_str!("test_error"), &memgraph);
         });
     }
+
+    #[test]
+    fn test_panic_message() {
+        assert_eq!(
+            panic_message(&"invalid argument"),
+            CString::new("Procedure panicked: invalid argument").unwrap()
+        );
+        assert_eq!(
+            panic_message(&format!("invalid argument {}", 3)),
+            CString::new("Procedure panicked: invalid argument 3").unwrap()
+        );
+        assert_eq!(
+            panic_message(&5),
+            CString::new("Procedure panicked.").unwrap()
+        );
+    }
+
+    define_procedure!(panicking_procedure, |_: &Memgraph| -> Result<()> {
+        panic!("invalid argument {}", 3)
+    });
+
+    #[test]
+    #[serial]
+    fn test_procedure_panic_sets_error_msg() {
+        mock_mgp_once!(mgp_result_set_error_msg_context, |_, msg| unsafe {
+            assert_eq!(
+                CStr::from_ptr(msg),
+                c_str!("Procedure panicked: invalid argument 3")
+            );
+            crate::mgp::mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+        clear_procedure_signatures();
+        panicking_procedure(null_mut(), null_mut(), null_mut(), null_mut());
+    }
+
+    #[derive(Debug)]
+    enum TestModuleError {
+        InvalidArgument(i64),
+    }
+
+    impl std::fmt::Display for TestModuleError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                TestModuleError::InvalidArgument(value) => write!(f, "Invalid argument {}.", value),
+            }
+        }
+    }
+
+    define_module_error!(TestModuleError);
+
+    fn check_argument(value: i64) -> std::result::Result<i64, TestModuleError> {
+        Err(TestModuleError::InvalidArgument(value))
+    }
+
+    define_procedure!(module_error_procedure, |_: &Memgraph| -> Result<()> {
+        check_argument(3)?;
+        Ok(())
+    });
+
+    #[test]
+    fn test_module_error_conversion() {
+        let error: Error = TestModuleError::InvalidArgument(3).into();
+        assert_eq!(
+            error,
+            Error::ModuleError {
+                message: "Invalid argument 3.".to_string()
+            }
+        );
+        assert_eq!(error.to_string(), "Invalid argument 3.");
+    }
+
+    #[test]
+    #[serial]
+    fn test_procedure_module_error_sets_error_msg() {
+        mock_mgp_once!(mgp_result_set_error_msg_context, |_, msg| unsafe {
+            assert_eq!(CStr::from_ptr(msg), c_str!("Invalid argument 3."));
+            crate::mgp::mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+        clear_procedure_signatures();
+        module_error_procedure(null_mut(), null_mut(), null_mut(), null_mut());
+    }
 }
@@ -114,19 +114,29 @@ fn create_naive_time(
     )
 }
 
-fn create_mgp_local_time_parameters(from: &NaiveTime) -> mgp_local_time_parameters {
-    let eliminate_leap_seconds = |nanos: u32| {
-        const NANOS_PER_SECONDS: u32 = 1_000_000_000;
-        nanos % NANOS_PER_SECONDS
-    };
+/// Splits the sub-second nanoseconds of a [NaiveTime] into milliseconds and microseconds.
+///
+/// chrono represents a leap second as nanoseconds in the `1_000_000_000..2_000_000_000` range,
+/// while Memgraph has no leap seconds. The leap part is truncated, so the leap second maps onto
+/// the preceding second, e.g., `1_999_999_999` becomes `(999, 999)`. Nanoseconds below one
+/// microsecond are truncated as well.
+fn split_millis_micros(nanos: u32) -> (i32, i32) {
+    const NANOS_PER_SECONDS: u32 = 1_000_000_000;
+    let nanos = nanos % NANOS_PER_SECONDS;
+    (
+        (nanos / NANOS_PER_MILLIS) as i32,
+        (nanos % NANOS_PER_MILLIS / NANOS_PER_MICROS) as i32,
+    )
+}
 
-    let nanoseconds = eliminate_leap_seconds(from.nanosecond());
+fn create_mgp_local_time_parameters(from: &NaiveTime) -> mgp_local_time_parameters {
+    let (millisecond, microsecond) = split_millis_micros(from.nanosecond());
     mgp_local_time_parameters {
         hour: from.hour() as i32,
         minute: from.minute() as i32,
         second: from.second() as i32,
-        millisecond: (nanoseconds / NANOS_PER_MILLIS) as i32,
-        microsecond: (nanoseconds % NANOS_PER_MILLIS / NANOS_PER_MICROS) as i32,
+        millisecond,
+        microsecond,
     }
 }
 
@@ -248,6 +258,14 @@ impl LocalDateTime {
         from: &NaiveDateTime,
         memgraph: &Memgraph,
     ) -> Result<LocalDateTime> {
+        let year = from.year();
+        if !(MINIMUM_YEAR..=MAXIMUM_YEAR).contains(&year) {
+            return Err(Error::LocalDateTimeYearOutOfRange {
+                year,
+                min: MINIMUM_YEAR,
+                max: MAXIMUM_YEAR,
+            });
+        }
         let mut date_params = mgp_date_parameters {
             year: from.year(),
             month: from.month() as i32,
@@ -366,8 +384,7 @@ impl Duration {
                 *mut mgp_duration,
                 Error::UnableToCreateDurationFromChronoDuration,
                 ffi::mgp_duration_from_microseconds,
-                from.num_microseconds()
-                    .ok_or(Error::UnableToCreateDurationFromChronoDuration)?,
+                from.num_microseconds().ok_or(Error::DurationOverflow)?,
                 memgraph.memory_ptr()
             )?);
             Ok(duration)
@@ -17,7 +17,6 @@ use crate::memgraph::Memgraph;
 use crate::mgp::mock_ffi::*;
 use crate::testing::alloc::*;
 use crate::{mock_mgp_once, with_dummy};
-use libc::{c_void, free};
 use serial_test::serial;
 
 fn check_date_params<T: Datelike>(date_params: &mgp_date_parameters, date: &T) {
@@ -52,7 +51,7 @@ fn test_from_naive_date() {
             }
         );
         mock_mgp_once!(mgp_date_destroy_context, |ptr| unsafe {
-            free(ptr as *mut c_void);
+            free_mgp(ptr);
         });
 
         with_dummy!(|memgraph: &Memgraph| {
@@ -63,6 +62,7 @@ fn test_from_naive_date() {
     test_date(NaiveDate::from_ymd(1834, 1, 1));
     test_date(NaiveDate::from_ymd(1996, 12, 7));
     test_date(NaiveDate::from_ymd(9999, 12, 31));
+    assert_no_leaks();
 }
 
 #[test]
@@ -148,6 +148,18 @@ fn test_date_unable_to_allocate() {
     });
 }
 
+#[test]
+fn test_split_millis_micros() {
+    assert_eq!(split_millis_micros(0), (0, 0));
+    assert_eq!(split_millis_micros(444_555_666), (444, 555));
+    // Just below the leap second.
+    assert_eq!(split_millis_micros(999_999_999), (999, 999));
+    // The leap second is truncated onto the preceding second.
+    assert_eq!(split_millis_micros(1_000_000_000), (0, 0));
+    assert_eq!(split_millis_micros(1_000_001_001), (0, 1));
+    assert_eq!(split_millis_micros(1_999_999_999), (999, 999));
+}
+
 #[test]
 #[serial]
 fn test_from_naive_time() {
@@ -161,7 +173,7 @@ fn test_from_naive_time() {
             }
         );
         mock_mgp_once!(mgp_local_time_destroy_context, |ptr| unsafe {
-            free(ptr as *mut c_void);
+            free_mgp(ptr);
         });
 
         with_dummy!(|memgraph: &Memgraph| {
@@ -173,6 +185,7 @@ fn test_from_naive_time() {
     test_time(NaiveTime::from_hms_micro(1, 2, 3, 444_555), 444, 555);
     // Leaps seconds handling
     test_time(NaiveTime::from_hms_micro(23, 59, 59, 1_999_999), 999, 999);
+    assert_no_leaks();
 }
 
 #[test]
@@ -285,7 +298,7 @@ fn test_from_naive_date_time() {
             }
         );
         mock_mgp_once!(mgp_local_date_time_destroy_context, |ptr| unsafe {
-            free(ptr as *mut c_void);
+            free_mgp(ptr);
         });
 
         with_dummy!(|memgraph: &Memgraph| {
@@ -317,6 +330,7 @@ fn test_from_naive_date_time() {
         777,
         888,
     );
+    assert_no_leaks();
 }
 #[test]
 #[serial]
@@ -476,6 +490,28 @@ fn test_to_naive_date_time() {
     );
 }
 
+#[test]
+#[serial]
+fn test_invalid_local_date_time() {
+    let test_invalid_date_time = |date: NaiveDate| {
+        with_dummy!(|memgraph: &Memgraph| {
+            let date_time = date.and_hms(0, 0, 0);
+            let result = LocalDateTime::from_naive_date_time(&date_time, &memgraph);
+            assert!(result.is_err());
+            assert_eq!(
+                result.err().unwrap(),
+                Error::LocalDateTimeYearOutOfRange {
+                    year: date.year(),
+                    min: 0,
+                    max: 9999
+                }
+            );
+        });
+    };
+    test_invalid_date_time(NaiveDate::from_ymd(-1, 12, 31));
+    test_invalid_date_time(NaiveDate::from_ymd(10000, 1, 1));
+}
+
 #[test]
 #[serial]
 fn test_local_date_time_unable_to_allocate() {
@@ -508,7 +544,7 @@ fn test_from_chrono_duration() {
             }
         );
         mock_mgp_once!(mgp_duration_destroy_context, |ptr| unsafe {
-            free(ptr as *mut c_void);
+            free_mgp(ptr);
         });
 
         with_dummy!(|memgraph: &Memgraph| {
@@ -524,6 +560,7 @@ fn test_from_chrono_duration() {
     test_duration(chrono::Duration::microseconds(-(2i64.pow(31))));
     test_duration(chrono::Duration::microseconds(std::i64::MAX));
     test_duration(chrono::Duration::microseconds(std::i64::MIN));
+    assert_no_leaks();
 }
 
 #[test]
@@ -570,6 +607,23 @@ fn test_to_chrono_duration() {
     test_duration(chrono::Duration::microseconds(std::i64::MIN));
 }
 
+#[test]
+#[serial]
+fn test_invalid_duration() {
+    let test_invalid_duration = |duration: chrono::Duration| {
+        with_dummy!(|memgraph: &Memgraph| {
+            let result = Duration::from_chrono_duration(&duration, &memgraph);
+            assert!(result.is_err());
+            assert_eq!(result.err().unwrap(), Error::DurationOverflow);
+        });
+    };
+    // Both overflow the microseconds representation.
+    test_invalid_duration(
+        chrono::Duration::microseconds(i64::MAX) + chrono::Duration::microseconds(1),
+    );
+    test_invalid_duration(chrono::Duration::max_value());
+}
+
 #[test]
 #[serial]
 fn test_duration_unable_to_allocate() {
@@ -14,65 +14,106 @@
 
 #[cfg(test)]
 pub mod alloc {
-    use libc::malloc;
+    use libc::{c_void, free, malloc};
+    use std::cell::Cell;
     use std::mem::size_of;
 
     use crate::mgp::*;
 
+    thread_local! {
+        /// Number of allocations made by this module which haven't been freed yet. Each test
+        /// runs on its own thread, so tests don't see each other's allocations.
+        static OUTSTANDING_ALLOCATIONS: Cell<i64> = const { Cell::new(0) };
+    }
+
+    unsafe fn alloc_tracked<T>() -> *mut T {
+        OUTSTANDING_ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        malloc(size_of::<T>()) as *mut T
+    }
+
+    /// Frees a pointer returned by one of the `alloc_mgp_*` functions. Should be used inside the
+    /// mocked `mgp_*_destroy` functions.
+    pub(crate) unsafe fn free_mgp<T>(ptr: *mut T) {
+        OUTSTANDING_ALLOCATIONS.with(|count| count.set(count.get() - 1));
+        free(ptr as *mut c_void);
+    }
+
+    /// Returns the number of allocations on the current thread which weren't freed by [free_mgp].
+    pub(crate) fn alloc_count() -> i64 {
+        OUTSTANDING_ALLOCATIONS.with(|count| count.get())
+    }
+
+    /// Fails the test if any allocation on the current thread wasn't freed by [free_mgp].
+    pub(crate) fn assert_no_leaks() {
+        assert_eq!(alloc_count(), 0, "Some mgp allocations were not freed.");
+    }
+
     pub(crate) unsafe fn alloc_mgp_type() -> *mut mgp_type {
-        malloc(size_of::<mgp_type>()) as *mut mgp_type
+        alloc_tracked::<mgp_type>()
     }
 
     pub(crate) unsafe fn alloc_mgp_value() -> *mut mgp_value {
-        malloc(size_of::<mgp_value>()) as *mut mgp_value
+        alloc_tracked::<mgp_value>()
     }
 
     pub(crate) unsafe fn alloc_mgp_list() -> *mut mgp_list {
-        malloc(size_of::<mgp_list>()) as *mut mgp_list
+        alloc_tracked::<mgp_list>()
     }
 
     pub(crate) unsafe fn alloc_mgp_map() -> *mut mgp_map {
-        malloc(size_of::<mgp_map>()) as *mut mgp_map
+        alloc_tracked::<mgp_map>()
     }
 
     pub(crate) unsafe fn alloc_mgp_map_items_iterator() -> *mut mgp_map_items_iterator {
-        malloc(size_of::<mgp_map_items_iterator>()) as *mut mgp_map_items_iterator
+        alloc_tracked::<mgp_map_items_iterator>()
+    }
+
+    pub(crate) unsafe fn alloc_mgp_properties_iterator() -> *mut mgp_properties_iterator {
+        alloc_tracked::<mgp_properties_iterator>()
     }
 
     pub(crate) unsafe fn alloc_mgp_vertex() -> *mut mgp_vertex {
-        malloc(size_of::<mgp_vertex>()) as *mut mgp_vertex
+        alloc_tracked::<mgp_vertex>()
+    }
+
+    pub(crate) unsafe fn alloc_mgp_vertices_iterator() -> *mut mgp_vertices_iterator {
+        alloc_tracked::<mgp_vertices_iterator>()
     }
 
     pub(crate) unsafe fn alloc_mgp_edge() -> *mut mgp_edge {
-        malloc(size_of::<mgp_edge>()) as *mut mgp_edge
+        alloc_tracked::<mgp_edge>()
+    }
+
+    pub(crate) unsafe fn alloc_mgp_edges_iterator() -> *mut mgp_edges_iterator {
+        alloc_tracked::<mgp_edges_iterator>()
     }
 
     pub(crate) unsafe fn alloc_mgp_path() -> *mut mgp_path {
-        malloc(size_of::<mgp_path>()) as *mut mgp_path
+        alloc_tracked::<mgp_path>()
     }
 
     pub(crate) unsafe fn alloc_mgp_date() -> *mut mgp_date {
-        malloc(size_of::<mgp_date>()) as *mut mgp_date
+        alloc_tracked::<mgp_date>()
     }
 
     pub(crate) unsafe fn alloc_mgp_local_time() -> *mut mgp_local_time {
-        malloc(size_of::<mgp_local_time>()) as *mut mgp_local_time
+        alloc_tracked::<mgp_local_time>()
     }
 
     pub(crate) unsafe fn alloc_mgp_local_date_time() -> *mut mgp_local_date_time {
-        malloc(size_of::<mgp_local_date_time>()) as *mut mgp_local_date_time
+        alloc_tracked::<mgp_local_date_time>()
     }
 
     pub(crate) unsafe fn alloc_mgp_duration() -> *mut mgp_duration {
-        malloc(size_of::<mgp_duration>()) as *mut mgp_duration
+        alloc_tracked::<mgp_duration>()
     }
 
     pub(crate) unsafe fn alloc_mgp_proc() -> *mut mgp_proc {
-        malloc(size_of::<mgp_proc>()) as *mut mgp_proc
+        alloc_tracked::<mgp_proc>()
     }
 
     pub(crate) unsafe fn alloc_mgp_result_record() -> *mut mgp_result_record {
-        malloc(size_of::<mgp_result_record>()) as *mut mgp_result_record
+        alloc_tracked::<mgp_result_record>()
     }
 
     #[macro_export]
@@ -83,6 +124,37 @@ pub mod alloc {
         };
     }
 
+    /// Mocks the check of inserted result values in [crate::result::ResultRecord::insert_mgp_value],
+    /// one entry per insert in the call order: `None` for a value which isn't a double, `Some`
+    /// with the value of a double.
+    #[macro_export]
+    macro_rules! mock_mgp_value_doubles {
+        ($($double:expr),* $(,)?) => {
+            let doubles: Vec<Option<f64>> = vec![$($double),*];
+            let read_doubles: Vec<f64> = doubles.iter().filter_map(|double| *double).collect();
+            let ctx_is_double = mgp_value_is_double_context();
+            let mut is_double_calls = 0;
+            ctx_is_double
+                .expect()
+                .times(doubles.len())
+                .returning(move |_, result| unsafe {
+                    (*result) = doubles[is_double_calls].is_some() as std::os::raw::c_int;
+                    is_double_calls += 1;
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_get_double = mgp_value_get_double_context();
+            let mut get_double_calls = 0;
+            ctx_get_double
+                .expect()
+                .times(read_doubles.len())
+                .returning(move |_, result| unsafe {
+                    (*result) = read_doubles[get_double_calls];
+                    get_double_calls += 1;
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+        };
+    }
+
     #[macro_export]
     macro_rules! with_dummy {
         ($rs_test_func:expr) => {
@@ -141,3 +213,1306 @@ pub mod alloc {
         };
     }
 }
+
+#[cfg(test)]
+pub mod log {
+    use std::cell::{Cell, RefCell};
+    use std::io;
+
+    thread_local! {
+        /// Lines written by [crate::log] on the current thread, tests read them instead of stderr.
+        static LINES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        static FAIL_WRITES: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Replaces writing to stderr in the test builds of [crate::log].
+    pub(crate) fn write_line(line: &str) -> io::Result<()> {
+        if FAIL_WRITES.with(|fail| fail.get()) {
+            return Err(io::Error::other("Writes disabled by the test."));
+        }
+        LINES.with(|lines| lines.borrow_mut().push(line.to_string()));
+        Ok(())
+    }
+
+    /// Returns and forgets the lines written on the current thread.
+    pub(crate) fn take_lines() -> Vec<String> {
+        LINES.with(|lines| lines.borrow_mut().drain(..).collect())
+    }
+
+    /// Makes all following writes on the current thread fail.
+    pub(crate) fn fail_writes() {
+        FAIL_WRITES.with(|fail| fail.set(true));
+    }
+}
+
+/// Fake object store behind the mocked C API, the round-trip and the fake graph tests read and
+/// write real content through it.
+#[cfg(test)]
+pub mod fake_mgp {
+    use libc::c_void;
+    use std::ffi::CString;
+    use std::ptr::null_mut;
+    use std::sync::{Mutex, MutexGuard};
+
+    use crate::mgp::*;
+    use crate::testing::alloc::*;
+
+    /// Object behind a fake mgp pointer. Pointers are tracked allocations (see
+    /// [crate::testing::alloc]) which are never dereferenced, the content lives in [FAKE_OBJECTS].
+    ///
+    /// Ownership follows Memgraph: values own the objects they were made from, containers own copies
+    /// of the inserted values, paths own copies of their vertices and edges own their endpoints.
+    /// Vertices and edges iterators own all the objects they iterate over. Destroying an object
+    /// destroys everything it owns, so [assert_no_leaks] catches leaks and destroying an object
+    /// twice panics.
+    #[derive(Clone)]
+    pub enum FakeObject {
+        Value(FakeValue),
+        List(Vec<usize>),
+        Map(Vec<(CString, usize)>),
+        /// Map items returned by the iterator are the iterator pointer itself, Memgraph also keeps
+        /// the current item inside the iterator.
+        MapItems {
+            map: usize,
+            position: usize,
+        },
+        Vertex(i64),
+        /// Endpoints are the start and the end vertex, empty if the edge isn't read from a fake
+        /// graph.
+        Edge {
+            id: i64,
+            endpoints: Vec<usize>,
+        },
+        Path(Vec<usize>),
+        Date([i32; 3]),
+        LocalTime([i32; 5]),
+        LocalDateTime([i32; 3], [i32; 5]),
+        Duration(i64),
+        /// `position` is the index of the current vertex.
+        Vertices {
+            vertices: Vec<usize>,
+            position: usize,
+        },
+        /// `position` is the index of the current edge. If `failing` is set, moving to the next
+        /// edge fails.
+        Edges {
+            edges: Vec<usize>,
+            position: usize,
+            failing: bool,
+        },
+    }
+
+    #[derive(Clone)]
+    pub enum FakeValue {
+        Null,
+        Bool(i32),
+        Int(i64),
+        Double(f64),
+        String(CString),
+        Object(mgp_value_type, usize),
+    }
+
+    static FAKE_OBJECTS: Mutex<Vec<(usize, FakeObject)>> = Mutex::new(Vec::new());
+
+    pub fn fake_objects() -> MutexGuard<'static, Vec<(usize, FakeObject)>> {
+        FAKE_OBJECTS.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn fake_alloc<T>(object: FakeObject) -> *mut T {
+        let ptr = unsafe {
+            match object {
+                FakeObject::Value(_) => alloc_mgp_value() as usize,
+                FakeObject::List(_) => alloc_mgp_list() as usize,
+                FakeObject::Map(_) => alloc_mgp_map() as usize,
+                FakeObject::MapItems { .. } => alloc_mgp_map_items_iterator() as usize,
+                FakeObject::Vertex(_) => alloc_mgp_vertex() as usize,
+                FakeObject::Edge { .. } => alloc_mgp_edge() as usize,
+                FakeObject::Path(_) => alloc_mgp_path() as usize,
+                FakeObject::Date(_) => alloc_mgp_date() as usize,
+                FakeObject::LocalTime(_) => alloc_mgp_local_time() as usize,
+                FakeObject::LocalDateTime(..) => alloc_mgp_local_date_time() as usize,
+                FakeObject::Duration(_) => alloc_mgp_duration() as usize,
+                FakeObject::Vertices { .. } => alloc_mgp_vertices_iterator() as usize,
+                FakeObject::Edges { .. } => alloc_mgp_edges_iterator() as usize,
+            }
+        };
+        fake_objects().push((ptr, object));
+        ptr as *mut T
+    }
+
+    pub fn fake_position(objects: &[(usize, FakeObject)], ptr: usize) -> usize {
+        objects
+            .iter()
+            .position(|(allocated, _)| *allocated == ptr)
+            .expect("Fake mgp object used after it was destroyed.")
+    }
+
+    pub fn fake_get<T>(ptr: *mut T) -> FakeObject {
+        let objects = fake_objects();
+        objects[fake_position(&objects, ptr as usize)].1.clone()
+    }
+
+    /// Calls `f` with the stored object, e.g. to modify it or to return a pointer into it.
+    pub fn fake_with<T, R>(ptr: *mut T, f: impl FnOnce(&mut FakeObject) -> R) -> R {
+        let mut objects = fake_objects();
+        let position = fake_position(&objects, ptr as usize);
+        f(&mut objects[position].1)
+    }
+
+    /// Destroys the object together with all objects it owns.
+    pub fn fake_destroy<T>(ptr: *mut T) {
+        let object = {
+            let mut objects = fake_objects();
+            let position = objects
+                .iter()
+                .position(|(allocated, _)| *allocated == ptr as usize)
+                .expect("Fake mgp object destroyed twice.");
+            objects.remove(position).1
+        };
+        match object {
+            FakeObject::Value(FakeValue::Object(_, object)) => fake_destroy(object as *mut c_void),
+            FakeObject::List(values) => values
+                .into_iter()
+                .for_each(|value| fake_destroy(value as *mut mgp_value)),
+            FakeObject::Map(items) => items
+                .into_iter()
+                .for_each(|(_, value)| fake_destroy(value as *mut mgp_value)),
+            FakeObject::Path(vertices)
+            | FakeObject::Edge {
+                endpoints: vertices,
+                ..
+            }
+            | FakeObject::Vertices { vertices, .. } => vertices
+                .into_iter()
+                .for_each(|vertex| fake_destroy(vertex as *mut mgp_vertex)),
+            FakeObject::Edges { edges, .. } => edges
+                .into_iter()
+                .for_each(|edge| fake_destroy(edge as *mut mgp_edge)),
+            _ => {}
+        }
+        unsafe { free_mgp(ptr) };
+    }
+
+    /// Copies the object together with all objects it owns.
+    pub fn fake_copy<T>(ptr: *mut T) -> *mut T {
+        let copy = match fake_get(ptr) {
+            FakeObject::Value(FakeValue::Object(value_type, object)) => FakeObject::Value(
+                FakeValue::Object(value_type, fake_copy(object as *mut c_void) as usize),
+            ),
+            FakeObject::List(values) => FakeObject::List(
+                values
+                    .into_iter()
+                    .map(|value| fake_copy(value as *mut mgp_value) as usize)
+                    .collect(),
+            ),
+            FakeObject::Map(items) => FakeObject::Map(
+                items
+                    .into_iter()
+                    .map(|(key, value)| (key, fake_copy(value as *mut mgp_value) as usize))
+                    .collect(),
+            ),
+            FakeObject::Path(vertices) => FakeObject::Path(
+                vertices
+                    .into_iter()
+                    .map(|vertex| fake_copy(vertex as *mut mgp_vertex) as usize)
+                    .collect(),
+            ),
+            FakeObject::Edge { id, endpoints } => FakeObject::Edge {
+                id,
+                endpoints: endpoints
+                    .into_iter()
+                    .map(|vertex| fake_copy(vertex as *mut mgp_vertex) as usize)
+                    .collect(),
+            },
+            object => object,
+        };
+        fake_alloc(copy)
+    }
+
+    pub fn fake_value(ptr: *mut mgp_value) -> FakeValue {
+        match fake_get(ptr) {
+            FakeObject::Value(value) => value,
+            _ => panic!("Expected a fake value."),
+        }
+    }
+
+    pub fn fake_value_object<T>(ptr: *mut mgp_value) -> *mut T {
+        match fake_value(ptr) {
+            FakeValue::Object(_, object) => object as *mut T,
+            _ => panic!("Expected a fake value holding an object."),
+        }
+    }
+
+    pub fn fake_make_value(value: FakeValue, result: *mut *mut mgp_value) -> mgp_error {
+        unsafe { (*result) = fake_alloc(FakeObject::Value(value)) };
+        mgp_error::MGP_ERROR_NO_ERROR
+    }
+
+    /// Returns the current object of a vertices or edges iterator, moving to the next one first if
+    /// `advance` is set. The result is null once the iterator is exhausted.
+    pub fn fake_iterator_item<T, R>(
+        iterator: *mut T,
+        advance: bool,
+        result: *mut *mut R,
+    ) -> mgp_error {
+        fake_with(iterator, |object| {
+            let (items, position, failing) = match object {
+                FakeObject::Vertices { vertices, position } => (vertices, position, false),
+                FakeObject::Edges {
+                    edges,
+                    position,
+                    failing,
+                } => (edges, position, *failing),
+                _ => panic!("Expected a fake vertices or edges iterator."),
+            };
+            if advance {
+                if failing {
+                    return mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE;
+                }
+                *position += 1;
+            }
+            unsafe {
+                (*result) = items
+                    .get(*position)
+                    .map_or(null_mut(), |item| *item as *mut R);
+            }
+            mgp_error::MGP_ERROR_NO_ERROR
+        })
+    }
+
+    pub fn fake_map_items(ptr: *mut mgp_map_item) -> (usize, usize) {
+        match fake_get(ptr) {
+            FakeObject::MapItems { map, position } => (map, position),
+            _ => panic!("Expected a fake map items iterator."),
+        }
+    }
+
+    pub fn fake_map_item(
+        iterator: *mut mgp_map_items_iterator,
+        result: *mut *mut mgp_map_item,
+    ) -> mgp_error {
+        let (map, position) = fake_map_items(iterator as *mut mgp_map_item);
+        let size = match fake_get(map as *mut mgp_map) {
+            FakeObject::Map(items) => items.len(),
+            _ => panic!("Expected a fake map."),
+        };
+        unsafe {
+            (*result) = if position < size {
+                iterator as *mut mgp_map_item
+            } else {
+                null_mut()
+            };
+        }
+        mgp_error::MGP_ERROR_NO_ERROR
+    }
+
+    #[macro_export]
+    macro_rules! mock_fake_local_date_time_getter {
+        ($c_func_name:ident, date, $index:expr) => {
+            let $c_func_name = $c_func_name();
+            $c_func_name.expect().returning(|date_time, result| unsafe {
+                if let $crate::testing::fake_mgp::FakeObject::LocalDateTime(date, _) =
+                    $crate::testing::fake_mgp::fake_get(date_time)
+                {
+                    (*result) = date[$index];
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+        };
+        ($c_func_name:ident, time, $index:expr) => {
+            let $c_func_name = $c_func_name();
+            $c_func_name.expect().returning(|date_time, result| unsafe {
+                if let $crate::testing::fake_mgp::FakeObject::LocalDateTime(_, time) =
+                    $crate::testing::fake_mgp::fake_get(date_time)
+                {
+                    (*result) = time[$index];
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+        };
+    }
+
+    /// Backs the value related part of the C API by [crate::testing::fake_mgp]. The functions could
+    /// be called any number of times, the tests check the content and
+    /// [crate::testing::alloc::assert_no_leaks] instead.
+    #[macro_export]
+    macro_rules! mock_fake_mgp {
+        () => {
+            $crate::testing::fake_mgp::fake_objects().clear();
+            // VALUE
+            let ctx_value_make_null = mgp_value_make_null_context();
+            ctx_value_make_null.expect().returning(|_, result| {
+                $crate::testing::fake_mgp::fake_make_value(
+                    $crate::testing::fake_mgp::FakeValue::Null,
+                    result,
+                )
+            });
+            let ctx_value_make_bool = mgp_value_make_bool_context();
+            ctx_value_make_bool.expect().returning(|value, _, result| {
+                $crate::testing::fake_mgp::fake_make_value(
+                    $crate::testing::fake_mgp::FakeValue::Bool(value),
+                    result,
+                )
+            });
+            let ctx_value_make_int = mgp_value_make_int_context();
+            ctx_value_make_int.expect().returning(|value, _, result| {
+                $crate::testing::fake_mgp::fake_make_value(
+                    $crate::testing::fake_mgp::FakeValue::Int(value),
+                    result,
+                )
+            });
+            let ctx_value_make_double = mgp_value_make_double_context();
+            ctx_value_make_double
+                .expect()
+                .returning(|value, _, result| {
+                    $crate::testing::fake_mgp::fake_make_value(
+                        $crate::testing::fake_mgp::FakeValue::Double(value),
+                        result,
+                    )
+                });
+            let ctx_value_make_string = mgp_value_make_string_context();
+            ctx_value_make_string
+                .expect()
+                .returning(|value, _, result| unsafe {
+                    $crate::testing::fake_mgp::fake_make_value(
+                        $crate::testing::fake_mgp::FakeValue::String(
+                            std::ffi::CStr::from_ptr(value).into(),
+                        ),
+                        result,
+                    )
+                });
+            let ctx_value_make_list = mgp_value_make_list_context();
+            ctx_value_make_list.expect().returning(|list, result| {
+                let value = $crate::testing::fake_mgp::FakeValue::Object(
+                    mgp_value_type::MGP_VALUE_TYPE_LIST,
+                    list as usize,
+                );
+                $crate::testing::fake_mgp::fake_make_value(value, result)
+            });
+            let ctx_value_make_map = mgp_value_make_map_context();
+            ctx_value_make_map.expect().returning(|map, result| {
+                let value = $crate::testing::fake_mgp::FakeValue::Object(
+                    mgp_value_type::MGP_VALUE_TYPE_MAP,
+                    map as usize,
+                );
+                $crate::testing::fake_mgp::fake_make_value(value, result)
+            });
+            let ctx_value_make_vertex = mgp_value_make_vertex_context();
+            ctx_value_make_vertex.expect().returning(|vertex, result| {
+                let value = $crate::testing::fake_mgp::FakeValue::Object(
+                    mgp_value_type::MGP_VALUE_TYPE_VERTEX,
+                    vertex as usize,
+                );
+                $crate::testing::fake_mgp::fake_make_value(value, result)
+            });
+            let ctx_value_make_edge = mgp_value_make_edge_context();
+            ctx_value_make_edge.expect().returning(|edge, result| {
+                let value = $crate::testing::fake_mgp::FakeValue::Object(
+                    mgp_value_type::MGP_VALUE_TYPE_EDGE,
+                    edge as usize,
+                );
+                $crate::testing::fake_mgp::fake_make_value(value, result)
+            });
+            let ctx_value_make_path = mgp_value_make_path_context();
+            ctx_value_make_path.expect().returning(|path, result| {
+                let value = $crate::testing::fake_mgp::FakeValue::Object(
+                    mgp_value_type::MGP_VALUE_TYPE_PATH,
+                    path as usize,
+                );
+                $crate::testing::fake_mgp::fake_make_value(value, result)
+            });
+            let ctx_value_make_date = mgp_value_make_date_context();
+            ctx_value_make_date.expect().returning(|date, result| {
+                let value = $crate::testing::fake_mgp::FakeValue::Object(
+                    mgp_value_type::MGP_VALUE_TYPE_DATE,
+                    date as usize,
+                );
+                $crate::testing::fake_mgp::fake_make_value(value, result)
+            });
+            let ctx_value_make_local_time = mgp_value_make_local_time_context();
+            ctx_value_make_local_time
+                .expect()
+                .returning(|time, result| {
+                    let value = $crate::testing::fake_mgp::FakeValue::Object(
+                        mgp_value_type::MGP_VALUE_TYPE_LOCAL_TIME,
+                        time as usize,
+                    );
+                    $crate::testing::fake_mgp::fake_make_value(value, result)
+                });
+            let ctx_value_make_local_date_time = mgp_value_make_local_date_time_context();
+            ctx_value_make_local_date_time
+                .expect()
+                .returning(|date_time, result| {
+                    let value = $crate::testing::fake_mgp::FakeValue::Object(
+                        mgp_value_type::MGP_VALUE_TYPE_LOCAL_DATE_TIME,
+                        date_time as usize,
+                    );
+                    $crate::testing::fake_mgp::fake_make_value(value, result)
+                });
+            let ctx_value_make_duration = mgp_value_make_duration_context();
+            ctx_value_make_duration
+                .expect()
+                .returning(|duration, result| {
+                    let value = $crate::testing::fake_mgp::FakeValue::Object(
+                        mgp_value_type::MGP_VALUE_TYPE_DURATION,
+                        duration as usize,
+                    );
+                    $crate::testing::fake_mgp::fake_make_value(value, result)
+                });
+            let ctx_value_get_type = mgp_value_get_type_context();
+            ctx_value_get_type
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = match $crate::testing::fake_mgp::fake_value(value) {
+                        $crate::testing::fake_mgp::FakeValue::Null => {
+                            mgp_value_type::MGP_VALUE_TYPE_NULL
+                        }
+                        $crate::testing::fake_mgp::FakeValue::Bool(_) => {
+                            mgp_value_type::MGP_VALUE_TYPE_BOOL
+                        }
+                        $crate::testing::fake_mgp::FakeValue::Int(_) => {
+                            mgp_value_type::MGP_VALUE_TYPE_INT
+                        }
+                        $crate::testing::fake_mgp::FakeValue::Double(_) => {
+                            mgp_value_type::MGP_VALUE_TYPE_DOUBLE
+                        }
+                        $crate::testing::fake_mgp::FakeValue::String(_) => {
+                            mgp_value_type::MGP_VALUE_TYPE_STRING
+                        }
+                        $crate::testing::fake_mgp::FakeValue::Object(value_type, _) => value_type,
+                    };
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_bool = mgp_value_get_bool_context();
+            ctx_value_get_bool
+                .expect()
+                .returning(|value, result| unsafe {
+                    if let $crate::testing::fake_mgp::FakeValue::Bool(x) =
+                        $crate::testing::fake_mgp::fake_value(value)
+                    {
+                        (*result) = x;
+                    }
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_int = mgp_value_get_int_context();
+            ctx_value_get_int
+                .expect()
+                .returning(|value, result| unsafe {
+                    if let $crate::testing::fake_mgp::FakeValue::Int(x) =
+                        $crate::testing::fake_mgp::fake_value(value)
+                    {
+                        (*result) = x;
+                    }
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_double = mgp_value_get_double_context();
+            ctx_value_get_double
+                .expect()
+                .returning(|value, result| unsafe {
+                    if let $crate::testing::fake_mgp::FakeValue::Double(x) =
+                        $crate::testing::fake_mgp::fake_value(value)
+                    {
+                        (*result) = x;
+                    }
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_string = mgp_value_get_string_context();
+            ctx_value_get_string
+                .expect()
+                .returning(|value, result| unsafe {
+                    // The string lives in the store, so the returned pointer stays valid.
+                    $crate::testing::fake_mgp::fake_with(value, |object| {
+                        if let $crate::testing::fake_mgp::FakeObject::Value(
+                            $crate::testing::fake_mgp::FakeValue::String(x),
+                        ) = object
+                        {
+                            (*result) = x.as_ptr();
+                        }
+                    });
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_list = mgp_value_get_list_context();
+            ctx_value_get_list
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_map = mgp_value_get_map_context();
+            ctx_value_get_map
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_vertex = mgp_value_get_vertex_context();
+            ctx_value_get_vertex
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_edge = mgp_value_get_edge_context();
+            ctx_value_get_edge
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_path = mgp_value_get_path_context();
+            ctx_value_get_path
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_date = mgp_value_get_date_context();
+            ctx_value_get_date
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_local_time = mgp_value_get_local_time_context();
+            ctx_value_get_local_time
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_local_date_time = mgp_value_get_local_date_time_context();
+            ctx_value_get_local_date_time
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_get_duration = mgp_value_get_duration_context();
+            ctx_value_get_duration
+                .expect()
+                .returning(|value, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_value_object(value);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_value_destroy = mgp_value_destroy_context();
+            ctx_value_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            // LIST
+            let ctx_list_make_empty = mgp_list_make_empty_context();
+            ctx_list_make_empty
+                .expect()
+                .returning(|_, _, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_alloc(
+                        $crate::testing::fake_mgp::FakeObject::List(Vec::new()),
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_list_append = mgp_list_append_context();
+            ctx_list_append.expect().returning(|list, value| {
+                let copy = $crate::testing::fake_mgp::fake_copy(value) as usize;
+                $crate::testing::fake_mgp::fake_with(list, |object| {
+                    if let $crate::testing::fake_mgp::FakeObject::List(values) = object {
+                        values.push(copy);
+                    }
+                });
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_list_size = mgp_list_size_context();
+            ctx_list_size.expect().returning(|list, result| unsafe {
+                if let $crate::testing::fake_mgp::FakeObject::List(values) =
+                    $crate::testing::fake_mgp::fake_get(list)
+                {
+                    (*result) = values.len() as u64;
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_list_at = mgp_list_at_context();
+            ctx_list_at
+                .expect()
+                .returning(|list, index, result| unsafe {
+                    match $crate::testing::fake_mgp::fake_get(list) {
+                        $crate::testing::fake_mgp::FakeObject::List(values)
+                            if (index as usize) < values.len() =>
+                        {
+                            (*result) = values[index as usize] as *mut mgp_value;
+                            mgp_error::MGP_ERROR_NO_ERROR
+                        }
+                        _ => mgp_error::MGP_ERROR_OUT_OF_RANGE,
+                    }
+                });
+            let ctx_list_destroy = mgp_list_destroy_context();
+            ctx_list_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            // MAP
+            let ctx_map_make_empty = mgp_map_make_empty_context();
+            ctx_map_make_empty.expect().returning(|_, result| unsafe {
+                (*result) = $crate::testing::fake_mgp::fake_alloc(
+                    $crate::testing::fake_mgp::FakeObject::Map(Vec::new()),
+                );
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_map_insert = mgp_map_insert_context();
+            ctx_map_insert.expect().returning(|map, key, value| unsafe {
+                let copy = $crate::testing::fake_mgp::fake_copy(value) as usize;
+                $crate::testing::fake_mgp::fake_with(map, |object| {
+                    if let $crate::testing::fake_mgp::FakeObject::Map(items) = object {
+                        items.push((std::ffi::CStr::from_ptr(key).into(), copy));
+                    }
+                });
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_map_size = mgp_map_size_context();
+            ctx_map_size.expect().returning(|map, result| unsafe {
+                if let $crate::testing::fake_mgp::FakeObject::Map(items) =
+                    $crate::testing::fake_mgp::fake_get(map)
+                {
+                    (*result) = items.len() as u64;
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_map_at = mgp_map_at_context();
+            ctx_map_at.expect().returning(|map, key, result| unsafe {
+                (*result) = std::ptr::null_mut();
+                if let $crate::testing::fake_mgp::FakeObject::Map(items) =
+                    $crate::testing::fake_mgp::fake_get(map)
+                {
+                    if let Some((_, value)) = items
+                        .iter()
+                        .find(|(k, _)| k.as_c_str() == std::ffi::CStr::from_ptr(key))
+                    {
+                        (*result) = *value as *mut mgp_value;
+                    }
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_map_iter_items = mgp_map_iter_items_context();
+            ctx_map_iter_items
+                .expect()
+                .returning(|map, _, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_alloc(
+                        $crate::testing::fake_mgp::FakeObject::MapItems {
+                            map: map as usize,
+                            position: 0,
+                        },
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_map_items_iterator_get = mgp_map_items_iterator_get_context();
+            ctx_map_items_iterator_get
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_map_item);
+            let ctx_map_items_iterator_next = mgp_map_items_iterator_next_context();
+            ctx_map_items_iterator_next
+                .expect()
+                .returning(|iterator, result| {
+                    $crate::testing::fake_mgp::fake_with(iterator, |object| {
+                        if let $crate::testing::fake_mgp::FakeObject::MapItems {
+                            position, ..
+                        } = object
+                        {
+                            *position += 1;
+                        }
+                    });
+                    $crate::testing::fake_mgp::fake_map_item(iterator, result)
+                });
+            let ctx_map_item_key = mgp_map_item_key_context();
+            ctx_map_item_key.expect().returning(|item, result| unsafe {
+                let (map, position) = $crate::testing::fake_mgp::fake_map_items(item);
+                // The key lives in the store, so the returned pointer stays valid.
+                $crate::testing::fake_mgp::fake_with(map as *mut mgp_map, |object| {
+                    if let $crate::testing::fake_mgp::FakeObject::Map(items) = object {
+                        (*result) = items[position].0.as_ptr();
+                    }
+                });
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_map_item_value = mgp_map_item_value_context();
+            ctx_map_item_value
+                .expect()
+                .returning(|item, result| unsafe {
+                    let (map, position) = $crate::testing::fake_mgp::fake_map_items(item);
+                    if let $crate::testing::fake_mgp::FakeObject::Map(items) =
+                        $crate::testing::fake_mgp::fake_get(map as *mut mgp_map)
+                    {
+                        (*result) = items[position].1 as *mut mgp_value;
+                    }
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_map_items_iterator_destroy = mgp_map_items_iterator_destroy_context();
+            ctx_map_items_iterator_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            let ctx_map_destroy = mgp_map_destroy_context();
+            ctx_map_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            // VERTEX, EDGE, PATH
+            let ctx_vertex_copy = mgp_vertex_copy_context();
+            ctx_vertex_copy
+                .expect()
+                .returning(|vertex, _, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_copy(vertex);
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_vertex_get_id = mgp_vertex_get_id_context();
+            ctx_vertex_get_id
+                .expect()
+                .returning(|vertex, result| unsafe {
+                    if let $crate::testing::fake_mgp::FakeObject::Vertex(id) =
+                        $crate::testing::fake_mgp::fake_get(vertex)
+                    {
+                        (*result).as_int = id;
+                    }
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_vertex_destroy = mgp_vertex_destroy_context();
+            ctx_vertex_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            let ctx_edge_copy = mgp_edge_copy_context();
+            ctx_edge_copy.expect().returning(|edge, _, result| unsafe {
+                (*result) = $crate::testing::fake_mgp::fake_copy(edge);
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_edge_get_id = mgp_edge_get_id_context();
+            ctx_edge_get_id.expect().returning(|edge, result| unsafe {
+                if let $crate::testing::fake_mgp::FakeObject::Edge { id, .. } =
+                    $crate::testing::fake_mgp::fake_get(edge)
+                {
+                    (*result).as_int = id;
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_edge_destroy = mgp_edge_destroy_context();
+            ctx_edge_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            let ctx_path_make_with_start = mgp_path_make_with_start_context();
+            ctx_path_make_with_start
+                .expect()
+                .returning(|vertex, _, result| unsafe {
+                    let start = $crate::testing::fake_mgp::fake_copy(vertex) as usize;
+                    (*result) = $crate::testing::fake_mgp::fake_alloc(
+                        $crate::testing::fake_mgp::FakeObject::Path(vec![start]),
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_path_copy = mgp_path_copy_context();
+            ctx_path_copy.expect().returning(|path, _, result| unsafe {
+                (*result) = $crate::testing::fake_mgp::fake_copy(path);
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_path_size = mgp_path_size_context();
+            ctx_path_size.expect().returning(|path, result| unsafe {
+                if let $crate::testing::fake_mgp::FakeObject::Path(vertices) =
+                    $crate::testing::fake_mgp::fake_get(path)
+                {
+                    (*result) = vertices.len() as u64 - 1;
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_path_vertex_at = mgp_path_vertex_at_context();
+            ctx_path_vertex_at
+                .expect()
+                .returning(|path, index, result| unsafe {
+                    match $crate::testing::fake_mgp::fake_get(path) {
+                        $crate::testing::fake_mgp::FakeObject::Path(vertices)
+                            if (index as usize) < vertices.len() =>
+                        {
+                            (*result) = vertices[index as usize] as *mut mgp_vertex;
+                            mgp_error::MGP_ERROR_NO_ERROR
+                        }
+                        _ => mgp_error::MGP_ERROR_OUT_OF_RANGE,
+                    }
+                });
+            let ctx_path_destroy = mgp_path_destroy_context();
+            ctx_path_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            // TEMPORAL
+            let ctx_date_from_parameters = mgp_date_from_parameters_context();
+            ctx_date_from_parameters
+                .expect()
+                .returning(|params, _, result| unsafe {
+                    let params = &*params;
+                    (*result) = $crate::testing::fake_mgp::fake_alloc(
+                        $crate::testing::fake_mgp::FakeObject::Date([
+                            params.year,
+                            params.month,
+                            params.day,
+                        ]),
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_date_get_year = mgp_date_get_year_context();
+            ctx_date_get_year.expect().returning(|date, result| unsafe {
+                if let $crate::testing::fake_mgp::FakeObject::Date(date) =
+                    $crate::testing::fake_mgp::fake_get(date)
+                {
+                    (*result) = date[0];
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_date_get_month = mgp_date_get_month_context();
+            ctx_date_get_month
+                .expect()
+                .returning(|date, result| unsafe {
+                    if let $crate::testing::fake_mgp::FakeObject::Date(date) =
+                        $crate::testing::fake_mgp::fake_get(date)
+                    {
+                        (*result) = date[1];
+                    }
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_date_get_day = mgp_date_get_day_context();
+            ctx_date_get_day.expect().returning(|date, result| unsafe {
+                if let $crate::testing::fake_mgp::FakeObject::Date(date) =
+                    $crate::testing::fake_mgp::fake_get(date)
+                {
+                    (*result) = date[2];
+                }
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            let ctx_date_destroy = mgp_date_destroy_context();
+            ctx_date_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            let ctx_local_time_from_parameters = mgp_local_time_from_parameters_context();
+            ctx_local_time_from_parameters
+                .expect()
+                .returning(|params, _, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_alloc(
+                        $crate::testing::fake_mgp::FakeObject::LocalTime(
+                            $crate::testing::fake_mgp::fake_time_parameters(&*params),
+                        ),
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_local_time_timestamp = mgp_local_time_timestamp_context();
+            ctx_local_time_timestamp
+                .expect()
+                .returning(|time, result| unsafe {
+                    if let $crate::testing::fake_mgp::FakeObject::LocalTime(
+                        [hour, minute, second, millisecond, microsecond],
+                    ) = $crate::testing::fake_mgp::fake_get(time)
+                    {
+                        let seconds = (hour * 60 + minute) * 60 + second;
+                        (*result) = seconds as i64 * 1_000_000
+                            + millisecond as i64 * 1_000
+                            + microsecond as i64;
+                    }
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_local_time_destroy = mgp_local_time_destroy_context();
+            ctx_local_time_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            let ctx_local_date_time_from_parameters = mgp_local_date_time_from_parameters_context();
+            ctx_local_date_time_from_parameters
+                .expect()
+                .returning(|params, _, result| unsafe {
+                    let date = &*(*params).date_parameters;
+                    let time = $crate::testing::fake_mgp::fake_time_parameters(
+                        &*(*params).local_time_parameters,
+                    );
+                    (*result) = $crate::testing::fake_mgp::fake_alloc(
+                        $crate::testing::fake_mgp::FakeObject::LocalDateTime(
+                            [date.year, date.month, date.day],
+                            time,
+                        ),
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            $crate::mock_fake_local_date_time_getter!(
+                mgp_local_date_time_get_year_context,
+                date,
+                0
+            );
+            $crate::mock_fake_local_date_time_getter!(
+                mgp_local_date_time_get_month_context,
+                date,
+                1
+            );
+            $crate::mock_fake_local_date_time_getter!(mgp_local_date_time_get_day_context, date, 2);
+            $crate::mock_fake_local_date_time_getter!(
+                mgp_local_date_time_get_hour_context,
+                time,
+                0
+            );
+            $crate::mock_fake_local_date_time_getter!(
+                mgp_local_date_time_get_minute_context,
+                time,
+                1
+            );
+            $crate::mock_fake_local_date_time_getter!(
+                mgp_local_date_time_get_second_context,
+                time,
+                2
+            );
+            $crate::mock_fake_local_date_time_getter!(
+                mgp_local_date_time_get_millisecond_context,
+                time,
+                3
+            );
+            $crate::mock_fake_local_date_time_getter!(
+                mgp_local_date_time_get_microsecond_context,
+                time,
+                4
+            );
+            let ctx_local_date_time_destroy = mgp_local_date_time_destroy_context();
+            ctx_local_date_time_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+            let ctx_duration_from_microseconds = mgp_duration_from_microseconds_context();
+            ctx_duration_from_microseconds
+                .expect()
+                .returning(|micros, _, result| unsafe {
+                    (*result) = $crate::testing::fake_mgp::fake_alloc(
+                        $crate::testing::fake_mgp::FakeObject::Duration(micros),
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_duration_get_microseconds = mgp_duration_get_microseconds_context();
+            ctx_duration_get_microseconds
+                .expect()
+                .returning(|duration, result| unsafe {
+                    if let $crate::testing::fake_mgp::FakeObject::Duration(micros) =
+                        $crate::testing::fake_mgp::fake_get(duration)
+                    {
+                        (*result) = micros;
+                    }
+                    mgp_error::MGP_ERROR_NO_ERROR
+                });
+            let ctx_duration_destroy = mgp_duration_destroy_context();
+            ctx_duration_destroy
+                .expect()
+                .returning($crate::testing::fake_mgp::fake_destroy);
+        };
+    }
+
+    pub fn fake_time_parameters(params: &mgp_local_time_parameters) -> [i32; 5] {
+        [
+            params.hour,
+            params.minute,
+            params.second,
+            params.millisecond,
+            params.microsecond,
+        ]
+    }
+}
+
+/// Fake graph with three vertices, the C API calls reading it are mocked by
+/// [crate::mock_fake_graph].
+#[cfg(test)]
+pub mod fake_graph {
+    use crate::mgp::*;
+    use crate::testing::fake_mgp::*;
+
+    /// Vertex ids of the fake graph.
+    pub const FAKE_VERTICES: [i64; 3] = [1, 2, 3];
+
+    pub enum FakeWeight {
+        Missing,
+        Int(i64),
+        Double(f64),
+        Bool(bool),
+    }
+
+    /// Edge of the fake graph, its id is the index + 1.
+    pub struct FakeEdge {
+        pub from: i64,
+        pub to: i64,
+        pub edge_type: &'static [u8],
+        pub weight: FakeWeight,
+    }
+
+    /// 1 -KNOWS(0.5)-> 2, 1 -KNOWS(2)-> 3, 2 -LIKES-> 3
+    pub const FAKE_EDGES: [FakeEdge; 3] = [
+        FakeEdge {
+            from: 1,
+            to: 2,
+            edge_type: b"KNOWS\0",
+            weight: FakeWeight::Double(0.5),
+        },
+        FakeEdge {
+            from: 1,
+            to: 3,
+            edge_type: b"KNOWS\0",
+            weight: FakeWeight::Int(2),
+        },
+        FakeEdge {
+            from: 2,
+            to: 3,
+            edge_type: b"LIKES\0",
+            weight: FakeWeight::Missing,
+        },
+    ];
+
+    /// 1 -KNOWS-> 1, 1 -KNOWS-> 2
+    pub const FAKE_EDGES_SELF_LOOP: [FakeEdge; 2] = [
+        FakeEdge {
+            from: 1,
+            to: 1,
+            edge_type: b"KNOWS\0",
+            weight: FakeWeight::Missing,
+        },
+        FakeEdge {
+            from: 1,
+            to: 2,
+            edge_type: b"KNOWS\0",
+            weight: FakeWeight::Missing,
+        },
+    ];
+
+    /// 1 -KNOWS-> 2, 2 -KNOWS-> 1, 1 -KNOWS-> 1
+    pub const FAKE_EDGES_BOTH_WAYS: [FakeEdge; 3] = [
+        FakeEdge {
+            from: 1,
+            to: 2,
+            edge_type: b"KNOWS\0",
+            weight: FakeWeight::Missing,
+        },
+        FakeEdge {
+            from: 2,
+            to: 1,
+            edge_type: b"KNOWS\0",
+            weight: FakeWeight::Missing,
+        },
+        FakeEdge {
+            from: 1,
+            to: 1,
+            edge_type: b"KNOWS\0",
+            weight: FakeWeight::Missing,
+        },
+    ];
+
+    pub const FAKE_EDGES_BOOL_WEIGHT: [FakeEdge; 1] = [FakeEdge {
+        from: 1,
+        to: 2,
+        edge_type: b"KNOWS\0",
+        weight: FakeWeight::Bool(true),
+    }];
+
+    /// Returns the graph edge behind a fake edge, the edge id is its index + 1.
+    pub fn fake_edge(edges: &'static [FakeEdge], ptr: *mut mgp_edge) -> &'static FakeEdge {
+        match fake_get(ptr) {
+            FakeObject::Edge { id, .. } => &edges[id as usize - 1],
+            _ => panic!("Expected a fake edge."),
+        }
+    }
+
+    /// Returns the start (`index` 0) or the end (`index` 1) vertex of a fake graph edge.
+    pub fn fake_endpoint(ptr: *mut mgp_edge, index: usize) -> *mut mgp_vertex {
+        match fake_get(ptr) {
+            FakeObject::Edge { endpoints, .. } => endpoints[index] as *mut mgp_vertex,
+            _ => panic!("Expected a fake edge."),
+        }
+    }
+
+    pub fn fake_weight(edge: &FakeEdge) -> FakeValue {
+        match edge.weight {
+            FakeWeight::Missing => FakeValue::Null,
+            FakeWeight::Int(weight) => FakeValue::Int(weight),
+            FakeWeight::Double(weight) => FakeValue::Double(weight),
+            FakeWeight::Bool(weight) => FakeValue::Bool(weight as i32),
+        }
+    }
+
+    pub fn fake_vertices() -> *mut mgp_vertices_iterator {
+        let vertices = FAKE_VERTICES
+            .iter()
+            .map(|id| fake_alloc::<mgp_vertex>(FakeObject::Vertex(*id)) as usize)
+            .collect();
+        fake_alloc(FakeObject::Vertices {
+            vertices,
+            position: 0,
+        })
+    }
+
+    /// Returns an iterator over the outgoing or the incoming edges of the vertex. The outgoing
+    /// edges of `failing_vertex` fail after the first edge.
+    pub fn fake_vertex_edges(
+        edges: &'static [FakeEdge],
+        vertex: *mut mgp_vertex,
+        outgoing: bool,
+        failing_vertex: Option<i64>,
+    ) -> *mut mgp_edges_iterator {
+        let id = match fake_get(vertex) {
+            FakeObject::Vertex(id) => id,
+            _ => panic!("Expected a fake vertex."),
+        };
+        let vertex_edges = edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| {
+                if outgoing {
+                    edge.from == id
+                } else {
+                    edge.to == id
+                }
+            })
+            .map(|(index, edge)| {
+                let endpoints = vec![
+                    fake_alloc::<mgp_vertex>(FakeObject::Vertex(edge.from)) as usize,
+                    fake_alloc::<mgp_vertex>(FakeObject::Vertex(edge.to)) as usize,
+                ];
+                let id = index as i64 + 1;
+                fake_alloc::<mgp_edge>(FakeObject::Edge { id, endpoints }) as usize
+            })
+            .collect();
+        fake_alloc(FakeObject::Edges {
+            edges: vertex_edges,
+            position: 0,
+            failing: outgoing && failing_vertex == Some(id),
+        })
+    }
+
+    /// Same as [crate::mock_mgp_once], but the function could be called any number of times.
+    #[macro_export]
+    macro_rules! mock_mgp {
+        ($c_func_name:ident, $rs_return_func:expr) => {
+            let $c_func_name = $c_func_name();
+            $c_func_name.expect().returning($rs_return_func);
+        };
+    }
+
+    /// Mocks the C API calls used to read a graph with [FAKE_VERTICES] and the given edges, on top
+    /// of the fake object store of [crate::mock_fake_mgp]. The expectations live until the end of
+    /// the calling scope.
+    ///
+    /// If `failing_vertex` is given, iterating its outgoing edges fails after the first edge.
+    #[macro_export]
+    macro_rules! mock_fake_graph {
+        ($edges:expr) => {
+            $crate::mock_fake_graph!($edges, None);
+        };
+        ($edges:expr, $failing_vertex:expr) => {
+            let edges: &'static [$crate::testing::fake_graph::FakeEdge] = &$edges;
+            let failing_vertex: Option<i64> = $failing_vertex;
+
+            $crate::mock_fake_mgp!();
+            $crate::mock_mgp!(mgp_must_abort_context, |_| 0);
+            $crate::mock_mgp!(mgp_graph_iter_vertices_context, |_, _, iterator| unsafe {
+                (*iterator) = $crate::testing::fake_graph::fake_vertices();
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            $crate::mock_mgp!(mgp_vertices_iterator_get_context, |iterator, vertex| {
+                $crate::testing::fake_mgp::fake_iterator_item(iterator, false, vertex)
+            });
+            $crate::mock_mgp!(mgp_vertices_iterator_next_context, |iterator, vertex| {
+                $crate::testing::fake_mgp::fake_iterator_item(iterator, true, vertex)
+            });
+            $crate::mock_mgp!(
+                mgp_vertices_iterator_destroy_context,
+                $crate::testing::fake_mgp::fake_destroy
+            );
+            $crate::mock_mgp!(
+                mgp_vertex_iter_out_edges_context,
+                move |vertex, _, iterator| unsafe {
+                    (*iterator) = $crate::testing::fake_graph::fake_vertex_edges(
+                        edges,
+                        vertex,
+                        true,
+                        failing_vertex,
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                }
+            );
+            $crate::mock_mgp!(
+                mgp_vertex_iter_in_edges_context,
+                move |vertex, _, iterator| unsafe {
+                    (*iterator) = $crate::testing::fake_graph::fake_vertex_edges(
+                        edges,
+                        vertex,
+                        false,
+                        failing_vertex,
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                }
+            );
+            $crate::mock_mgp!(mgp_edges_iterator_get_context, |iterator, edge| {
+                $crate::testing::fake_mgp::fake_iterator_item(iterator, false, edge)
+            });
+            $crate::mock_mgp!(mgp_edges_iterator_next_context, |iterator, edge| {
+                $crate::testing::fake_mgp::fake_iterator_item(iterator, true, edge)
+            });
+            $crate::mock_mgp!(
+                mgp_edges_iterator_destroy_context,
+                $crate::testing::fake_mgp::fake_destroy
+            );
+            $crate::mock_mgp!(mgp_edge_get_type_context, move |edge, edge_type| unsafe {
+                (*edge_type).name = $crate::testing::fake_graph::fake_edge(edges, edge)
+                    .edge_type
+                    .as_ptr() as *const std::os::raw::c_char;
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            $crate::mock_mgp!(mgp_edge_get_from_context, |edge, vertex| unsafe {
+                (*vertex) = $crate::testing::fake_graph::fake_endpoint(edge, 0);
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            $crate::mock_mgp!(mgp_edge_get_to_context, |edge, vertex| unsafe {
+                (*vertex) = $crate::testing::fake_graph::fake_endpoint(edge, 1);
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+            // Each edge has only the weight property, so the name isn't checked.
+            $crate::mock_mgp!(
+                mgp_edge_get_property_context,
+                move |edge, _, _, value| unsafe {
+                    let edge = $crate::testing::fake_graph::fake_edge(edges, edge);
+                    (*value) = $crate::testing::fake_mgp::fake_alloc(
+                        $crate::testing::fake_mgp::FakeObject::Value(
+                            $crate::testing::fake_graph::fake_weight(edge),
+                        ),
+                    );
+                    mgp_error::MGP_ERROR_NO_ERROR
+                }
+            );
+        };
+    }
+}
+
+/// Asserts that two [crate::value::Value]s are equal, optionally comparing floats with the given
+/// absolute tolerance. On failure, the panic message describes the first difference.
+///
+/// Example
+///
+/// ```no run
+/// assert_value_eq!(args.value_at(0)?, Value::Float(0.3), 1e-9);
+/// ```
+#[macro_export]
+macro_rules! assert_value_eq {
+    ($left:expr, $right:expr) => {
+        $crate::assert_value_eq!($left, $right, 0.0)
+    };
+    ($left:expr, $right:expr, $epsilon:expr) => {
+        if let Some(diff) = $crate::value::value_mismatch(&$left, &$right, $epsilon) {
+            panic!("assertion failed: `(left == right)`\n{}", diff);
+        }
+    };
+}
+
+/// Asserts that two records, given as slices of field name and [crate::value::Value] pairs, are
+/// equal. Works the same way as [assert_value_eq].
+///
+/// Example
+///
+/// ```no run
+/// assert_record_eq!(
+///     [("node_id", Value::Int(1)), ("score", score)],
+///     [("node_id", Value::Int(1)), ("score", Value::Float(0.5))],
+///     1e-9
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_record_eq {
+    ($left:expr, $right:expr) => {
+        $crate::assert_record_eq!($left, $right, 0.0)
+    };
+    ($left:expr, $right:expr, $epsilon:expr) => {
+        if let Some(diff) = $crate::value::record_mismatch(&$left, &$right, $epsilon) {
+            panic!("assertion failed: `(left == right)`\n{}", diff);
+        }
+    };
+}
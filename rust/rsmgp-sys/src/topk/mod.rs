@@ -0,0 +1,109 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Streaming top-k selection that keeps only the best `k` items instead of sorting all of them.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// Compares two items, the greater item is the better one.
+pub type Comparator<T> = fn(&T, &T) -> Ordering;
+
+struct Entry<T> {
+    item: T,
+    compare: Comparator<T>,
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.compare)(&self.item, &other.item) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(&self.item, &other.item)
+    }
+}
+
+/// Keeps the `k` greatest items pushed so far according to `compare`.
+///
+/// Memory is bounded by `k` and every push is `O(log k)`. The worst kept item is replaced only by
+/// a strictly greater one, so out of equal items the ones pushed first are kept. The comparator
+/// should break ties (e.g. by node id) if the output has to be deterministic.
+pub struct TopK<T> {
+    k: usize,
+    compare: Comparator<T>,
+    heap: BinaryHeap<Reverse<Entry<T>>>,
+}
+
+impl<T> TopK<T> {
+    pub fn new(k: usize, compare: Comparator<T>) -> TopK<T> {
+        TopK {
+            k,
+            compare,
+            heap: BinaryHeap::with_capacity(k),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(Entry {
+                item,
+                compare: self.compare,
+            }));
+            return;
+        }
+        if let Some(mut worst) = self.heap.peek_mut() {
+            if (self.compare)(&item, &worst.0.item) == Ordering::Greater {
+                worst.0.item = item;
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the kept items ordered from the greatest to the smallest.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|entry| entry.0.item)
+            .collect()
+    }
+}
+
+impl<T> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
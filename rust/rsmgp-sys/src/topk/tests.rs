@@ -0,0 +1,68 @@
+// Copyright (c) 2016-2021 Memgraph Ltd. [https://memgraph.com]
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use super::*;
+
+fn by_score(a: &(i64, f64), b: &(i64, f64)) -> Ordering {
+    a.1.partial_cmp(&b.1)
+        .unwrap_or(Ordering::Equal)
+        .then(b.0.cmp(&a.0))
+}
+
+#[test]
+fn test_keeps_greatest_sorted() {
+    let mut top = TopK::new(3, |a: &i64, b: &i64| a.cmp(b));
+    top.extend(vec![5, 1, 9, 3, 7, 2, 8]);
+    assert_eq!(top.len(), 3);
+    assert_eq!(top.into_sorted_vec(), vec![9, 8, 7]);
+}
+
+#[test]
+fn test_custom_comparator() {
+    let mut top = TopK::new(2, by_score);
+    top.extend(vec![(1, 0.2), (2, 0.9), (3, 0.5), (4, 0.9)]);
+    assert_eq!(top.into_sorted_vec(), vec![(2, 0.9), (4, 0.9)]);
+
+    let mut top = TopK::new(2, |a: &i64, b: &i64| b.cmp(a));
+    top.extend(vec![5, 1, 9, 3]);
+    assert_eq!(top.into_sorted_vec(), vec![1, 3]);
+}
+
+#[test]
+fn test_ties_keep_first_pushed() {
+    let mut top = TopK::new(2, |a: &(i64, f64), b: &(i64, f64)| {
+        a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal)
+    });
+    top.extend(vec![(1, 0.5), (2, 0.5), (3, 0.5)]);
+    let mut kept: Vec<i64> = top.into_sorted_vec().iter().map(|e| e.0).collect();
+    kept.sort_unstable();
+    assert_eq!(kept, vec![1, 2]);
+}
+
+#[test]
+fn test_fewer_items_than_k() {
+    let mut top = TopK::new(5, |a: &i64, b: &i64| a.cmp(b));
+    top.extend(vec![2, 3, 1]);
+    assert_eq!(top.into_sorted_vec(), vec![3, 2, 1]);
+}
+
+#[test]
+fn test_zero_k() {
+    let mut top = TopK::new(0, |a: &i64, b: &i64| a.cmp(b));
+    top.push(1);
+    assert!(top.is_empty());
+    assert!(top.into_sorted_vec().is_empty());
+}
@@ -15,6 +15,7 @@
 
 use std::convert::From;
 use std::ffi::{CStr, CString};
+use std::fmt;
 use std::os::raw::c_char;
 
 use crate::edge::*;
@@ -104,6 +105,16 @@ impl MgpValue {
         }
     }
 
+    /// Returns the underlying [mgp_value] pointer for interop with other C libraries that the safe
+    /// API doesn't cover. The ownership stays with the value.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is valid only while the value is alive and must not be destroyed by the caller.
+    pub unsafe fn as_ptr(&self) -> *mut mgp_value {
+        self.ptr
+    }
+
     pub(crate) fn mgp_ptr(&self) -> *mut mgp_value {
         self.ptr
     }
@@ -457,6 +468,114 @@ pub enum Value {
 }
 
 impl Value {
+    /// Returns `true` for [Value::Null], e.g., a procedure argument passed as `null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    /// Returns the value of a [Value::Bool], `None` for any other variant.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [Value::Int], `None` for any other variant.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [Value::Float], `None` for any other variant.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [Value::String], `None` for any other variant.
+    pub fn as_str(&self) -> Option<&CStr> {
+        match self {
+            Value::String(value) => Some(value.as_c_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [Value::Vertex], `None` for any other variant.
+    pub fn as_vertex(&self) -> Option<&Vertex> {
+        match self {
+            Value::Vertex(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [Value::Edge], `None` for any other variant.
+    pub fn as_edge(&self) -> Option<&Edge> {
+        match self {
+            Value::Edge(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [Value::Path], `None` for any other variant.
+    pub fn as_path(&self) -> Option<&Path> {
+        match self {
+            Value::Path(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the list of a [Value::List], `None` for any other variant, e.g.
+    /// `args.value_at(0)?.as_list().ok_or(...)?`.
+    pub fn as_list(&self) -> Option<&List> {
+        match self {
+            Value::List(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [Value::Map], `None` for any other variant.
+    pub fn as_map(&self) -> Option<&Map> {
+        match self {
+            Value::Map(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the Cypher name of the value type, e.g., for messages like "expected INTEGER, got
+    /// STRING".
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Null => "NULL",
+            Value::Bool(_) => "BOOLEAN",
+            Value::Int(_) => "INTEGER",
+            Value::Float(_) => "FLOAT",
+            Value::String(_) => "STRING",
+            Value::Vertex(_) => "NODE",
+            Value::Edge(_) => "RELATIONSHIP",
+            Value::Path(_) => "PATH",
+            Value::List(_) => "LIST",
+            Value::Map(_) => "MAP",
+            Value::Date(_) => "DATE",
+            Value::LocalTime(_) => "LOCAL_TIME",
+            Value::LocalDateTime(_) => "LOCAL_DATE_TIME",
+            Value::Duration(_) => "DURATION",
+        }
+    }
+
+    /// Returns a copy which owns all its underlying objects, nested lists and maps included.
+    ///
+    /// The C API doesn't have `mgp_value_copy`, so the value is copied into a new [mgp_value] and
+    /// read back. The copy is independent of the source, e.g., a procedure argument could be
+    /// stored in a module struct.
+    pub fn deep_copy(&self, memgraph: &Memgraph) -> Result<Value> {
+        self.to_mgp_value(memgraph)?.to_value()
+    }
+
     pub fn to_mgp_value(&self, memgraph: &Memgraph) -> Result<MgpValue> {
         match self {
             Value::Null => MgpValue::make_null(&memgraph),
@@ -486,6 +605,304 @@ impl From<MgpValue> for Value {
     }
 }
 
+/// Rendered in place of a value or graph object which can't be read from Memgraph.
+const UNREADABLE: &str = "<unreadable>";
+
+/// Writes all path elements, alternating between vertices and edges. Elements which can't be read
+/// are written as `<unreadable>`.
+fn fmt_path(path: &Path, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Path(")?;
+    for index in 0..path.size() + 1 {
+        if index > 0 {
+            match path.edge_at(index - 1) {
+                Ok(edge) => write!(f, ", Edge({}), ", edge.id())?,
+                Err(_) => write!(f, ", {}, ", UNREADABLE)?,
+            }
+        }
+        match path.vertex_at(index) {
+            Ok(vertex) => write!(f, "Vertex({})", vertex.id())?,
+            Err(_) => write!(f, "{}", UNREADABLE)?,
+        }
+    }
+    write!(f, ")")
+}
+
+/// Renders the value in a human-readable form, e.g. `[1, 2, 3]` or `{k: v}`.
+///
+/// Containers are rendered recursively. Graph objects are rendered by their ids because the
+/// properties could be arbitrarily large. Values which can't be read from Memgraph are rendered as
+/// `<unreadable>` instead of failing the whole formatting.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(x) => write!(f, "{}", x),
+            Value::Int(x) => write!(f, "{}", x),
+            Value::Float(x) => write!(f, "{}", x),
+            Value::String(x) => write!(f, "{}", x.to_string_lossy()),
+            Value::Vertex(x) => write!(f, "Vertex({})", x.id()),
+            Value::Edge(x) => write!(f, "Edge({})", x.id()),
+            Value::Path(x) => fmt_path(x, f),
+            Value::List(x) => {
+                write!(f, "[")?;
+                match x.iter() {
+                    Ok(values) => {
+                        for (index, value) in values.enumerate() {
+                            if index > 0 {
+                                write!(f, ", ")?;
+                            }
+                            match value {
+                                Ok(value) => write!(f, "{}", value)?,
+                                Err(_) => write!(f, "{}", UNREADABLE)?,
+                            }
+                        }
+                    }
+                    Err(_) => write!(f, "{}", UNREADABLE)?,
+                }
+                write!(f, "]")
+            }
+            Value::Map(x) => {
+                write!(f, "{{")?;
+                match x.iter() {
+                    Ok(items) => {
+                        for (index, item) in items.enumerate() {
+                            if index > 0 {
+                                write!(f, ", ")?;
+                            }
+                            write!(f, "{}: {}", item.key.to_string_lossy(), item.value)?;
+                        }
+                    }
+                    Err(_) => write!(f, "{}", UNREADABLE)?,
+                }
+                write!(f, "}}")
+            }
+            Value::Date(x) => write!(f, "{}", x),
+            Value::LocalTime(x) => write!(f, "{}", x),
+            Value::LocalDateTime(x) => write!(f, "{}", x),
+            Value::Duration(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "Null"),
+            Value::Bool(x) => f.debug_tuple("Bool").field(x).finish(),
+            Value::Int(x) => f.debug_tuple("Int").field(x).finish(),
+            Value::Float(x) => f.debug_tuple("Float").field(x).finish(),
+            Value::String(x) => f.debug_tuple("String").field(x).finish(),
+            Value::Vertex(x) => f.debug_tuple("Vertex").field(&x.id().0).finish(),
+            Value::Edge(x) => f.debug_tuple("Edge").field(&x.id().0).finish(),
+            Value::Path(x) => fmt_path(x, f),
+            Value::List(x) => {
+                let values: Vec<Value> = x
+                    .iter()
+                    .map_err(|_| fmt::Error)?
+                    .collect::<Result<_>>()
+                    .map_err(|_| fmt::Error)?;
+                f.debug_tuple("List").field(&values).finish()
+            }
+            Value::Map(x) => {
+                let items: Vec<MapItem> = x.iter().map_err(|_| fmt::Error)?.collect();
+                let entries = items.iter().map(|item| (&item.key, &item.value));
+                write!(f, "Map(")?;
+                f.debug_map().entries(entries).finish()?;
+                write!(f, ")")
+            }
+            Value::Date(x) => f.debug_tuple("Date").field(x).finish(),
+            Value::LocalTime(x) => f.debug_tuple("LocalTime").field(x).finish(),
+            Value::LocalDateTime(x) => f.debug_tuple("LocalDateTime").field(x).finish(),
+            Value::Duration(x) => f.debug_tuple("Duration").field(x).finish(),
+        }
+    }
+}
+
+/// Structural equality: primitives are compared directly, containers element-wise, vertices and
+/// edges by their ids, paths by the ids of all their vertices and edges. Floats are compared exactly, use [crate::assert_value_eq] with a tolerance
+/// for computed results.
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Null, Value::Null) => true,
+            (Value::Bool(l), Value::Bool(r)) => l == r,
+            (Value::Int(l), Value::Int(r)) => l == r,
+            (Value::Float(l), Value::Float(r)) => l == r,
+            (Value::String(l), Value::String(r)) => l == r,
+            (Value::Vertex(l), Value::Vertex(r)) => l.id() == r.id(),
+            (Value::Edge(l), Value::Edge(r)) => l.id() == r.id(),
+            (Value::Path(l), Value::Path(r)) => {
+                l.size() == r.size()
+                    && (0..l.size() + 1).all(|index| {
+                        match (l.vertex_at(index), r.vertex_at(index)) {
+                            (Ok(lv), Ok(rv)) => lv.id() == rv.id(),
+                            _ => false,
+                        }
+                    })
+                    && (0..l.size()).all(|index| match (l.edge_at(index), r.edge_at(index)) {
+                        (Ok(le), Ok(re)) => le.id() == re.id(),
+                        _ => false,
+                    })
+            }
+            (Value::List(l), Value::List(r)) => {
+                l.size() == r.size()
+                    && (0..l.size()).all(|index| match (l.value_at(index), r.value_at(index)) {
+                        (Ok(lv), Ok(rv)) => lv == rv,
+                        _ => false,
+                    })
+            }
+            (Value::Map(l), Value::Map(r)) => {
+                l.size() == r.size()
+                    && match l.iter() {
+                        Ok(mut items) => items.all(|item| match r.at(&item.key) {
+                            Ok(rv) => item.value == rv,
+                            Err(_) => false,
+                        }),
+                        Err(_) => false,
+                    }
+            }
+            (Value::Date(l), Value::Date(r)) => l == r,
+            (Value::LocalTime(l), Value::LocalTime(r)) => l == r,
+            (Value::LocalDateTime(l), Value::LocalDateTime(r)) => l == r,
+            (Value::Duration(l), Value::Duration(r)) => l == r,
+            _ => false,
+        }
+    }
+}
+
+/// Short description of a value used in mismatch reports.
+fn describe_value(value: &Value) -> String {
+    match value {
+        Value::Null => "Null".to_string(),
+        Value::Bool(x) => format!("Bool({})", x),
+        Value::Int(x) => format!("Int({})", x),
+        Value::Float(x) => format!("Float({})", x),
+        Value::String(x) => format!("String({:?})", x),
+        Value::Vertex(x) => format!("Vertex(id={})", x.id()),
+        Value::Edge(x) => format!("Edge(id={})", x.id()),
+        Value::Path(x) => format!("Path(size={})", x.size()),
+        Value::List(x) => format!("List(size={})", x.size()),
+        Value::Map(x) => format!("Map(size={})", x.size()),
+        Value::Date(x) => format!("Date({})", x),
+        Value::LocalTime(x) => format!("LocalTime({})", x),
+        Value::LocalDateTime(x) => format!("LocalDateTime({})", x),
+        Value::Duration(x) => format!("Duration({})", x),
+    }
+}
+
+fn mismatch_at(location: &str, left: &Value, right: &Value) -> Option<String> {
+    Some(format!(
+        "{}: {} != {}",
+        location,
+        describe_value(left),
+        describe_value(right)
+    ))
+}
+
+fn value_mismatch_at(location: &str, left: &Value, right: &Value, epsilon: f64) -> Option<String> {
+    match (left, right) {
+        (Value::Null, Value::Null) => None,
+        (Value::Bool(l), Value::Bool(r)) if l == r => None,
+        (Value::Int(l), Value::Int(r)) if l == r => None,
+        (Value::Float(l), Value::Float(r)) if l == r || (l - r).abs() <= epsilon => None,
+        (Value::String(l), Value::String(r)) if l == r => None,
+        (Value::Vertex(l), Value::Vertex(r)) if l.id() == r.id() => None,
+        (Value::Edge(l), Value::Edge(r)) if l.id() == r.id() => None,
+        (Value::Path(l), Value::Path(r)) if l.size() == r.size() => {
+            for index in 0..l.size() + 1 {
+                match (l.vertex_at(index), r.vertex_at(index)) {
+                    (Ok(lv), Ok(rv)) if lv.id() == rv.id() => (),
+                    _ => return Some(format!("{}: paths differ at vertex {}", location, index)),
+                }
+            }
+            for index in 0..l.size() {
+                match (l.edge_at(index), r.edge_at(index)) {
+                    (Ok(le), Ok(re)) if le.id() == re.id() => (),
+                    _ => return Some(format!("{}: paths differ at edge {}", location, index)),
+                }
+            }
+            None
+        }
+        (Value::List(l), Value::List(r)) if l.size() == r.size() => {
+            for index in 0..l.size() {
+                let element_location = format!("{}[{}]", location, index);
+                match (l.value_at(index), r.value_at(index)) {
+                    (Ok(lv), Ok(rv)) => {
+                        if let Some(diff) = value_mismatch_at(&element_location, &lv, &rv, epsilon)
+                        {
+                            return Some(diff);
+                        }
+                    }
+                    _ => return Some(format!("{}: unable to read element", element_location)),
+                }
+            }
+            None
+        }
+        (Value::Map(l), Value::Map(r)) if l.size() == r.size() => {
+            let items = match l.iter() {
+                Ok(items) => items,
+                Err(_) => return Some(format!("{}: unable to iterate map", location)),
+            };
+            for item in items {
+                let item_location = format!("{}.{}", location, item.key.to_string_lossy());
+                match r.at(&item.key) {
+                    Ok(rv) => {
+                        if let Some(diff) =
+                            value_mismatch_at(&item_location, &item.value, &rv, epsilon)
+                        {
+                            return Some(diff);
+                        }
+                    }
+                    Err(_) => return Some(format!("{}: missing key on the right", item_location)),
+                }
+            }
+            None
+        }
+        (Value::Date(l), Value::Date(r)) if l == r => None,
+        (Value::LocalTime(l), Value::LocalTime(r)) if l == r => None,
+        (Value::LocalDateTime(l), Value::LocalDateTime(r)) if l == r => None,
+        (Value::Duration(l), Value::Duration(r)) if l == r => None,
+        _ => mismatch_at(location, left, right),
+    }
+}
+
+/// Compares two values and returns a description of the first difference, or `None` if they are
+/// equal. Floats are compared with the given absolute tolerance, vertices and edges by their ids.
+///
+/// Used by [crate::assert_value_eq] to produce readable assertion failures.
+pub fn value_mismatch(left: &Value, right: &Value, epsilon: f64) -> Option<String> {
+    value_mismatch_at("value", left, right, epsilon)
+}
+
+/// Compares two records given as field name and value pairs, the same way as [value_mismatch].
+///
+/// Used by [crate::assert_record_eq] to produce readable assertion failures.
+pub fn record_mismatch(
+    left: &[(&str, Value)],
+    right: &[(&str, Value)],
+    epsilon: f64,
+) -> Option<String> {
+    if left.len() != right.len() {
+        return Some(format!(
+            "record: {} fields != {} fields",
+            left.len(),
+            right.len()
+        ));
+    }
+    for ((left_name, left_value), (right_name, right_value)) in left.iter().zip(right.iter()) {
+        if left_name != right_name {
+            return Some(format!(
+                "record: field {} != field {}",
+                left_name, right_name
+            ));
+        }
+        if let Some(diff) = value_mismatch_at(left_name, left_value, right_value, epsilon) {
+            return Some(diff);
+        }
+    }
+    None
+}
+
 /// Creates copy of [mgp_value] object as a [Value] object.
 ///
 /// NOTE: If would be more optimal not to copy [mgp_list], [mgp_map] and [mgp_path], but that's not
@@ -506,7 +923,7 @@ pub(crate) unsafe fn mgp_raw_value_to_value(
     match invoke_mgp_func!(mgp_value_type, ffi::mgp_value_get_type, value).unwrap() {
         mgp_value_type::MGP_VALUE_TYPE_NULL => Ok(Value::Null),
         mgp_value_type::MGP_VALUE_TYPE_BOOL => Ok(Value::Bool(
-            invoke_mgp_func!(::std::os::raw::c_int, ffi::mgp_value_get_bool, value).unwrap() == 0,
+            invoke_mgp_func!(::std::os::raw::c_int, ffi::mgp_value_get_bool, value).unwrap() != 0,
         )),
         mgp_value_type::MGP_VALUE_TYPE_INT => Ok(Value::Int(
             invoke_mgp_func!(i64, ffi::mgp_value_get_int, value).unwrap(),
@@ -542,36 +959,46 @@ pub(crate) unsafe fn mgp_raw_value_to_value(
             invoke_mgp_func!(*mut mgp_map, ffi::mgp_value_get_map, value).unwrap(),
             &memgraph,
         )?)),
-        mgp_value_type::MGP_VALUE_TYPE_DATE => Ok(Value::Date(
-            temporal::Date::new(
+        // Temporal values are owned by the mgp_value, the wrappers are released before they are
+        // dropped to avoid destroying them twice.
+        mgp_value_type::MGP_VALUE_TYPE_DATE => {
+            let mut date = temporal::Date::new(
                 invoke_mgp_func!(*mut mgp_date, ffi::mgp_value_get_date, value).unwrap(),
-            )
-            .to_naive_date(),
-        )),
-        mgp_value_type::MGP_VALUE_TYPE_LOCAL_TIME => Ok(Value::LocalTime(
-            temporal::LocalTime::new(
+            );
+            let naive_date = date.to_naive_date();
+            date.set_mgp_ptr(std::ptr::null_mut());
+            Ok(Value::Date(naive_date))
+        }
+        mgp_value_type::MGP_VALUE_TYPE_LOCAL_TIME => {
+            let mut local_time = temporal::LocalTime::new(
                 invoke_mgp_func!(*mut mgp_local_time, ffi::mgp_value_get_local_time, value)
                     .unwrap(),
-            )
-            .to_naive_time(),
-        )),
-        mgp_value_type::MGP_VALUE_TYPE_LOCAL_DATE_TIME => Ok(Value::LocalDateTime(
-            temporal::LocalDateTime::new(
+            );
+            let naive_time = local_time.to_naive_time();
+            local_time.set_mgp_ptr(std::ptr::null_mut());
+            Ok(Value::LocalTime(naive_time))
+        }
+        mgp_value_type::MGP_VALUE_TYPE_LOCAL_DATE_TIME => {
+            let mut local_date_time = temporal::LocalDateTime::new(
                 invoke_mgp_func!(
                     *mut mgp_local_date_time,
                     ffi::mgp_value_get_local_date_time,
                     value
                 )
                 .unwrap(),
-            )
-            .to_naive_date_time(),
-        )),
-        mgp_value_type::MGP_VALUE_TYPE_DURATION => Ok(Value::Duration(
-            temporal::Duration::new(
+            );
+            let naive_date_time = local_date_time.to_naive_date_time();
+            local_date_time.set_mgp_ptr(std::ptr::null_mut());
+            Ok(Value::LocalDateTime(naive_date_time))
+        }
+        mgp_value_type::MGP_VALUE_TYPE_DURATION => {
+            let mut duration = temporal::Duration::new(
                 invoke_mgp_func!(*mut mgp_duration, ffi::mgp_value_get_duration, value).unwrap(),
-            )
-            .to_chrono_duration(),
-        )),
+            );
+            let chrono_duration = duration.to_chrono_duration();
+            duration.set_mgp_ptr(std::ptr::null_mut());
+            Ok(Value::Duration(chrono_duration))
+        }
     }
 }
 
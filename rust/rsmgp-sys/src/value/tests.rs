@@ -20,7 +20,10 @@ use std::ptr::null_mut;
 use super::*;
 use crate::memgraph::Memgraph;
 use crate::mgp::mock_ffi::*;
-use crate::{mock_mgp_once, with_dummy};
+use crate::testing::alloc::*;
+use crate::testing::fake_mgp::*;
+use crate::{mock_fake_mgp, mock_mgp_once, with_dummy};
+use libc::{c_void, free};
 
 #[test]
 #[serial]
@@ -274,3 +277,798 @@ fn test_to_mgp_value() {
         assert!(mgp_value.is_err());
     });
 }
+
+#[test]
+fn test_assert_value_eq_passes() {
+    crate::assert_value_eq!(Value::Int(3), Value::Int(3));
+    crate::assert_value_eq!(Value::Null, Value::Null);
+    crate::assert_value_eq!(Value::Float(0.1 + 0.2), Value::Float(0.3), 1e-9);
+    crate::assert_value_eq!(
+        Value::Float(f64::INFINITY),
+        Value::Float(f64::INFINITY),
+        1e-9
+    );
+    crate::assert_value_eq!(
+        Value::String(CString::new("text").unwrap()),
+        Value::String(CString::new("text").unwrap())
+    );
+}
+
+#[test]
+#[should_panic(expected = "value: Int(3) != Float(3)")]
+fn test_assert_value_eq_fails_on_type_mismatch() {
+    crate::assert_value_eq!(Value::Int(3), Value::Float(3.0));
+}
+
+#[test]
+#[should_panic(expected = "value: Float(0.5) != Float(0.6)")]
+fn test_assert_value_eq_fails_outside_tolerance() {
+    crate::assert_value_eq!(Value::Float(0.5), Value::Float(0.6), 1e-3);
+}
+
+#[test]
+fn test_assert_record_eq_passes() {
+    crate::assert_record_eq!(
+        [
+            ("node_id", Value::Int(1)),
+            ("score", Value::Float(0.30000001))
+        ],
+        [("node_id", Value::Int(1)), ("score", Value::Float(0.3))],
+        1e-6
+    );
+}
+
+#[test]
+#[should_panic(expected = "score: Float(0.5) != Float(0.25)")]
+fn test_assert_record_eq_fails_on_value() {
+    crate::assert_record_eq!(
+        [("node_id", Value::Int(1)), ("score", Value::Float(0.5))],
+        [("node_id", Value::Int(1)), ("score", Value::Float(0.25))]
+    );
+}
+
+#[test]
+#[should_panic(expected = "record: field node_id != field nod_id")]
+fn test_assert_record_eq_fails_on_field_name() {
+    crate::assert_record_eq!([("node_id", Value::Int(1))], [("nod_id", Value::Int(1))]);
+}
+
+#[test]
+fn test_display_primitives() {
+    assert_eq!(format!("{}", Value::Null), "null");
+    assert_eq!(format!("{}", Value::Bool(true)), "true");
+    assert_eq!(format!("{}", Value::Int(-3)), "-3");
+    assert_eq!(format!("{}", Value::Float(0.5)), "0.5");
+    assert_eq!(
+        format!("{}", Value::String(CString::new("text").unwrap())),
+        "text"
+    );
+}
+
+#[test]
+fn test_display_temporal() {
+    let date = NaiveDate::from_ymd(2021, 6, 1);
+    let time = NaiveTime::from_hms(12, 30, 15);
+    assert_eq!(format!("{}", Value::Date(date)), "2021-06-01");
+    assert_eq!(format!("{}", Value::LocalTime(time)), "12:30:15");
+    assert_eq!(
+        format!("{}", Value::LocalDateTime(date.and_time(time))),
+        "2021-06-01 12:30:15"
+    );
+    assert_eq!(
+        format!("{}", Value::Duration(chrono::Duration::seconds(90))),
+        "PT90S"
+    );
+}
+
+#[test]
+fn test_debug_primitives() {
+    assert_eq!(format!("{:?}", Value::Null), "Null");
+    assert_eq!(format!("{:?}", Value::Bool(false)), "Bool(false)");
+    assert_eq!(format!("{:?}", Value::Int(7)), "Int(7)");
+    assert_eq!(format!("{:?}", Value::Float(1.5)), "Float(1.5)");
+    assert_eq!(
+        format!("{:?}", Value::String(CString::new("text").unwrap())),
+        "String(\"text\")"
+    );
+    assert_eq!(
+        format!("{:?}", Value::Date(NaiveDate::from_ymd(2021, 6, 1))),
+        "Date(2021-06-01)"
+    );
+}
+
+#[test]
+fn test_type_name() {
+    let memgraph = Memgraph::new_default();
+    let date = NaiveDate::from_ymd(2021, 6, 1);
+    let time = NaiveTime::from_hms(13, 37, 42);
+    for (value, name) in [
+        (Value::Null, "NULL"),
+        (Value::Bool(true), "BOOLEAN"),
+        (Value::Int(1), "INTEGER"),
+        (Value::Float(0.5), "FLOAT"),
+        (Value::String(CString::new("a").unwrap()), "STRING"),
+        (Value::Vertex(Vertex::new(null_mut(), &memgraph)), "NODE"),
+        (
+            Value::Edge(Edge::new(null_mut(), &memgraph)),
+            "RELATIONSHIP",
+        ),
+        (Value::Path(Path::new(null_mut(), &memgraph)), "PATH"),
+        (Value::List(List::new(null_mut(), &memgraph)), "LIST"),
+        (Value::Map(Map::new(null_mut(), &memgraph)), "MAP"),
+        (Value::Date(date), "DATE"),
+        (Value::LocalTime(time), "LOCAL_TIME"),
+        (Value::LocalDateTime(date.and_time(time)), "LOCAL_DATE_TIME"),
+        (Value::Duration(chrono::Duration::seconds(1)), "DURATION"),
+    ] {
+        assert_eq!(value.type_name(), name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_format_graph_objects() {
+    let ctx_vertex_id = mgp_vertex_get_id_context();
+    ctx_vertex_id
+        .expect()
+        .times(2)
+        .returning(|_, vertex_id_ptr| unsafe {
+            (*vertex_id_ptr).as_int = 1;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_edge_id = mgp_edge_get_id_context();
+    ctx_edge_id
+        .expect()
+        .times(2)
+        .returning(|_, edge_id_ptr| unsafe {
+            (*edge_id_ptr).as_int = 5;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let vertex = Value::Vertex(Vertex::new(null_mut(), &memgraph));
+        let edge = Value::Edge(Edge::new(null_mut(), &memgraph));
+        assert_eq!(format!("{}", vertex), "Vertex(1)");
+        assert_eq!(format!("{:?}", vertex), "Vertex(1)");
+        assert_eq!(format!("{}", edge), "Edge(5)");
+        assert_eq!(format!("{:?}", edge), "Edge(5)");
+    });
+}
+
+#[test]
+#[serial]
+fn test_format_list() {
+    let ctx_size = mgp_list_size_context();
+    ctx_size.expect().times(6).returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 2;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_at = mgp_list_at_context();
+    ctx_at
+        .expect()
+        .times(4)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_get_type = mgp_value_get_type_context();
+    ctx_get_type
+        .expect()
+        .times(4)
+        .returning(|_, type_ptr| unsafe {
+            (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut get_int_calls = 0;
+    let ctx_get_int = mgp_value_get_int_context();
+    ctx_get_int
+        .expect()
+        .times(4)
+        .returning(move |_, int_ptr| unsafe {
+            get_int_calls += 1;
+            (*int_ptr) = if get_int_calls % 2 == 1 { 1 } else { 2 };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let list = Value::List(List::new(null_mut(), &memgraph));
+        assert_eq!(format!("{}", list), "[1, 2]");
+        assert_eq!(format!("{:?}", list), "List([Int(1), Int(2)])");
+    });
+}
+
+#[test]
+#[serial]
+fn test_format_map() {
+    let ctx_iter_items = mgp_map_iter_items_context();
+    ctx_iter_items
+        .expect()
+        .times(2)
+        .returning(|_, _, iter_ptr_ptr| unsafe {
+            (*iter_ptr_ptr) = alloc_mgp_map_items_iterator();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_iterator_get = mgp_map_items_iterator_get_context();
+    ctx_iterator_get
+        .expect()
+        .times(2)
+        .returning(|_, item_ptr_ptr| unsafe {
+            (*item_ptr_ptr) = std::ptr::NonNull::dangling().as_ptr();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_item_key = mgp_map_item_key_context();
+    ctx_item_key
+        .expect()
+        .times(2)
+        .returning(|_, key_ptr_ptr| unsafe {
+            (*key_ptr_ptr) = c_str!("k").as_ptr();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_item_value = mgp_map_item_value_context();
+    ctx_item_value
+        .expect()
+        .times(2)
+        .returning(|_, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_get_type = mgp_value_get_type_context();
+    ctx_get_type
+        .expect()
+        .times(2)
+        .returning(|_, type_ptr| unsafe {
+            (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_get_int = mgp_value_get_int_context();
+    ctx_get_int
+        .expect()
+        .times(2)
+        .returning(|_, int_ptr| unsafe {
+            (*int_ptr) = 3;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_iterator_next = mgp_map_items_iterator_next_context();
+    ctx_iterator_next
+        .expect()
+        .times(2)
+        .returning(|_, item_ptr_ptr| unsafe {
+            (*item_ptr_ptr) = null_mut();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let ctx_iterator_destroy = mgp_map_items_iterator_destroy_context();
+    ctx_iterator_destroy
+        .expect()
+        .times(2)
+        .returning(|ptr| unsafe {
+            free(ptr as *mut c_void);
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let map = Value::Map(Map::new(null_mut(), &memgraph));
+        assert_eq!(format!("{}", map), "{k: 3}");
+        assert_eq!(format!("{:?}", map), "Map({\"k\": Int(3)})");
+    });
+}
+
+#[test]
+#[serial]
+fn test_display_unreadable_list_value() {
+    let ctx_size = mgp_list_size_context();
+    ctx_size.expect().times(3).returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 2;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let mut list_at_calls = 0;
+    let ctx_at = mgp_list_at_context();
+    ctx_at.expect().times(2).returning(move |_, _, _| {
+        list_at_calls += 1;
+        if list_at_calls == 1 {
+            mgp_error::MGP_ERROR_OUT_OF_RANGE
+        } else {
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    });
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_int_context, |_, int_ptr| unsafe {
+        (*int_ptr) = 2;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let list = Value::List(List::new(null_mut(), &memgraph));
+        assert_eq!(list.to_string(), "[<unreadable>, 2]");
+    });
+}
+
+#[test]
+#[serial]
+fn test_display_unreadable_map() {
+    mock_mgp_once!(mgp_map_iter_items_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let map = Value::Map(Map::new(null_mut(), &memgraph));
+        assert_eq!(map.to_string(), "{<unreadable>}");
+    });
+}
+
+#[test]
+#[serial]
+fn test_display_unreadable_path() {
+    let ctx_size = mgp_path_size_context();
+    ctx_size.expect().times(1).returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_path_edge_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_OUT_OF_RANGE
+    });
+    let ctx_vertex_at = mgp_path_vertex_at_context();
+    ctx_vertex_at
+        .expect()
+        .times(2)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_OUT_OF_RANGE);
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let path = Value::Path(Path::new(null_mut(), &memgraph));
+        assert_eq!(
+            path.to_string(),
+            "Path(<unreadable>, <unreadable>, <unreadable>)"
+        );
+    });
+}
+
+#[test]
+fn test_eq_primitives() {
+    assert_eq!(Value::Null, Value::Null);
+    assert_eq!(Value::Bool(true), Value::Bool(true));
+    assert_ne!(Value::Bool(true), Value::Bool(false));
+    assert_eq!(Value::Int(42), Value::Int(42));
+    assert_ne!(Value::Int(42), Value::Int(43));
+    assert_ne!(Value::Int(1), Value::Float(1.0));
+    assert_eq!(Value::Float(0.5), Value::Float(0.5));
+    assert_ne!(Value::Float(f64::NAN), Value::Float(f64::NAN));
+    assert_eq!(Value::Float(f64::INFINITY), Value::Float(f64::INFINITY));
+    assert_ne!(Value::Float(f64::INFINITY), Value::Float(f64::NEG_INFINITY));
+    assert_eq!(
+        Value::String(CString::new("text").unwrap()),
+        Value::String(CString::new("text").unwrap())
+    );
+    assert_ne!(
+        Value::String(CString::new("text").unwrap()),
+        Value::String(CString::new("other").unwrap())
+    );
+    assert_ne!(Value::Null, Value::Int(0));
+}
+
+#[test]
+fn test_eq_temporal() {
+    let date = NaiveDate::from_ymd(2021, 6, 1);
+    let time = NaiveTime::from_hms(12, 30, 15);
+    assert_eq!(Value::Date(date), Value::Date(date));
+    assert_ne!(Value::Date(date), Value::Date(date.succ()));
+    assert_eq!(Value::LocalTime(time), Value::LocalTime(time));
+    assert_eq!(
+        Value::LocalDateTime(date.and_time(time)),
+        Value::LocalDateTime(date.and_time(time))
+    );
+    assert_ne!(
+        Value::Duration(chrono::Duration::seconds(1)),
+        Value::Duration(chrono::Duration::milliseconds(1))
+    );
+}
+
+#[test]
+#[serial]
+fn test_eq_vertices_by_id() {
+    let mut get_id_calls = 0;
+    let ctx_vertex_id = mgp_vertex_get_id_context();
+    ctx_vertex_id
+        .expect()
+        .times(4)
+        .returning(move |_, vertex_id_ptr| unsafe {
+            get_id_calls += 1;
+            // Ids are read in (left, right) pairs: (1, 1) and (1, 2).
+            (*vertex_id_ptr).as_int = if get_id_calls == 4 { 2 } else { 1 };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let left = Value::Vertex(Vertex::new(null_mut(), &memgraph));
+        let right = Value::Vertex(Vertex::new(null_mut(), &memgraph));
+        assert_eq!(left, right);
+        assert_ne!(left, right);
+    });
+}
+
+#[test]
+#[serial]
+fn test_eq_paths_by_edge_ids() {
+    let ctx_size = mgp_path_size_context();
+    ctx_size.expect().returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_vertex_at = mgp_path_vertex_at_context();
+    ctx_vertex_at
+        .expect()
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_vertex_copy = mgp_vertex_copy_context();
+    ctx_vertex_copy
+        .expect()
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_vertex_id = mgp_vertex_get_id_context();
+    ctx_vertex_id.expect().returning(|_, vertex_id_ptr| unsafe {
+        (*vertex_id_ptr).as_int = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_edge_at = mgp_path_edge_at_context();
+    ctx_edge_at
+        .expect()
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_edge_copy = mgp_edge_copy_context();
+    ctx_edge_copy
+        .expect()
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let mut get_edge_id_calls = 0;
+    let ctx_edge_id = mgp_edge_get_id_context();
+    ctx_edge_id
+        .expect()
+        .times(4)
+        .returning(move |_, edge_id_ptr| unsafe {
+            get_edge_id_calls += 1;
+            // Ids are read in (left, right) pairs, the paths share the vertices but not the edge.
+            (*edge_id_ptr).as_int = if get_edge_id_calls % 2 == 1 { 1 } else { 2 };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let left = Value::Path(Path::new(null_mut(), &memgraph));
+        let right = Value::Path(Path::new(null_mut(), &memgraph));
+        assert_ne!(left, right);
+        assert_eq!(
+            value_mismatch(&left, &right, 0.0).unwrap(),
+            "value: paths differ at edge 0"
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_eq_lists_element_wise() {
+    let ctx_size = mgp_list_size_context();
+    ctx_size.expect().returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_at = mgp_list_at_context();
+    ctx_at
+        .expect()
+        .times(2)
+        .returning(|_, _, _| mgp_error::MGP_ERROR_NO_ERROR);
+    let ctx_get_type = mgp_value_get_type_context();
+    ctx_get_type
+        .expect()
+        .times(2)
+        .returning(|_, type_ptr| unsafe {
+            (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_INT;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut get_int_calls = 0;
+    let ctx_get_int = mgp_value_get_int_context();
+    ctx_get_int
+        .expect()
+        .times(2)
+        .returning(move |_, int_ptr| unsafe {
+            get_int_calls += 1;
+            (*int_ptr) = get_int_calls;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let left = Value::List(List::new(null_mut(), &memgraph));
+        let right = Value::List(List::new(null_mut(), &memgraph));
+        assert_ne!(left, right);
+    });
+}
+
+#[test]
+#[serial]
+fn test_null_mgp_value_to_value() {
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_NULL;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let value = unsafe { mgp_raw_value_to_value(null_mut(), &memgraph).unwrap() };
+        assert!(value.is_null());
+        assert!(!Value::Int(0).is_null());
+    });
+}
+
+// Round-trip tests: each [Value] is converted into an [mgp_value] and back. The mocked C API is
+// backed by the fake object store of [crate::testing::fake_mgp], so the content really passes
+// through the FFI calls.
+fn round_trip(value: &Value, memgraph: &Memgraph) -> Value {
+    value.to_mgp_value(memgraph).unwrap().to_value().unwrap()
+}
+
+#[test]
+#[serial]
+fn test_round_trip_primitives() {
+    mock_fake_mgp!();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        for value in [
+            Value::Null,
+            Value::Bool(true),
+            Value::Bool(false),
+            Value::Int(-42),
+            Value::Float(0.25),
+            Value::String(CString::new("round trip").unwrap()),
+        ] {
+            assert_eq!(round_trip(&value, &memgraph), value);
+        }
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_round_trip_temporal() {
+    mock_fake_mgp!();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let date = NaiveDate::from_ymd(2021, 6, 1);
+        let time = NaiveTime::from_hms_micro(13, 37, 42, 123_456);
+        for value in [
+            Value::Date(date),
+            Value::LocalTime(time),
+            Value::LocalDateTime(date.and_time(time)),
+            Value::Duration(chrono::Duration::microseconds(90_061_000_001)),
+        ] {
+            assert_eq!(round_trip(&value, &memgraph), value);
+        }
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_round_trip_graph_objects() {
+    mock_fake_mgp!();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let vertex = Vertex::new(fake_alloc(FakeObject::Vertex(7)), &memgraph);
+        let edge = Edge::new(
+            fake_alloc(FakeObject::Edge {
+                id: 11,
+                endpoints: Vec::new(),
+            }),
+            &memgraph,
+        );
+        let path = Path::make_with_start(&vertex, &memgraph).unwrap();
+        for value in [Value::Vertex(vertex), Value::Edge(edge), Value::Path(path)] {
+            assert_eq!(round_trip(&value, &memgraph), value);
+        }
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_round_trip_nested_list() {
+    mock_fake_mgp!();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let inner = List::make_empty(2, &memgraph).unwrap();
+        inner.append(&Value::Float(0.5)).unwrap();
+        inner.append(&Value::Null).unwrap();
+        let list = List::make_empty(3, &memgraph).unwrap();
+        list.append(&Value::Int(1)).unwrap();
+        list.append(&Value::String(CString::new("a").unwrap()))
+            .unwrap();
+        list.append(&Value::List(inner)).unwrap();
+        let value = Value::List(list);
+
+        let result = round_trip(&value, &memgraph);
+        assert_eq!(result, value);
+        assert_eq!(format!("{}", result), "[1, a, [0.5, null]]");
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_round_trip_nested_map() {
+    mock_fake_mgp!();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let inner = List::make_empty(1, &memgraph).unwrap();
+        inner.append(&Value::Bool(true)).unwrap();
+        let map = Map::make_empty(&memgraph).unwrap();
+        map.insert(c_str!("a"), &Value::Int(1)).unwrap();
+        map.insert(c_str!("nested"), &Value::List(inner)).unwrap();
+        let value = Value::Map(map);
+
+        let result = round_trip(&value, &memgraph);
+        assert_eq!(result, value);
+        assert_eq!(format!("{}", result), "{a: 1, nested: [true]}");
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_round_trip_detects_corruption() {
+    mock_fake_mgp!();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let list = List::make_empty(1, &memgraph).unwrap();
+        list.append(&Value::Int(1)).unwrap();
+        let value = Value::List(list);
+        let result = round_trip(&value, &memgraph);
+
+        // The copy is independent of the original, so the difference has to be detected.
+        if let Value::List(copy) = &result {
+            copy.append(&Value::Int(2)).unwrap();
+        }
+        assert_ne!(result, value);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_read_graph_object_arguments() {
+    mock_fake_mgp!();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let vertex = Vertex::new(fake_alloc(FakeObject::Vertex(7)), &memgraph);
+        let edge = Edge::new(
+            fake_alloc(FakeObject::Edge {
+                id: 11,
+                endpoints: Vec::new(),
+            }),
+            &memgraph,
+        );
+        let path = Path::make_with_start(&vertex, &memgraph).unwrap();
+        let args = List::make_empty(3, &memgraph).unwrap();
+        for value in [Value::Vertex(vertex), Value::Edge(edge), Value::Path(path)] {
+            args.append(&value).unwrap();
+        }
+
+        // Procedure arguments registered as Type::Vertex, Type::Edge and Type::Path.
+        let procedure_memgraph = Memgraph::new(
+            args.mgp_ptr(),
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            null_mut(),
+        );
+        let procedure_args = procedure_memgraph.args().unwrap();
+        match procedure_args.value_at(0).unwrap() {
+            Value::Vertex(vertex) => assert_eq!(vertex.id(), 7),
+            _ => panic!("Expected a vertex argument."),
+        }
+        match procedure_args.value_at(1).unwrap() {
+            Value::Edge(edge) => assert_eq!(edge.id(), 11),
+            _ => panic!("Expected an edge argument."),
+        }
+        match procedure_args.value_at(2).unwrap() {
+            Value::Path(path) => assert_eq!(path.vertex_at(0).unwrap().id(), 7),
+            _ => panic!("Expected a path argument."),
+        }
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_deep_copy_nested_list() {
+    mock_fake_mgp!();
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let inner = List::make_empty(1, &memgraph).unwrap();
+        inner.append(&Value::Int(2)).unwrap();
+        let list = List::make_empty(3, &memgraph).unwrap();
+        list.append(&Value::Int(1)).unwrap();
+        list.append(&Value::List(inner)).unwrap();
+        let value = Value::List(list);
+
+        let copy = value.deep_copy(&memgraph).unwrap();
+        assert_eq!(copy, value);
+
+        if let Value::List(list) = &value {
+            list.append(&Value::Int(3)).unwrap();
+        }
+        assert_ne!(copy, value);
+        assert_eq!(format!("{}", copy), "[1, [2]]");
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_as_ptr() {
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    let memgraph = Memgraph::new_default();
+    unsafe {
+        let ptr = alloc_mgp_value();
+        let value = MgpValue::new(ptr, &memgraph);
+        assert_eq!(value.as_ptr(), ptr);
+    }
+    assert_no_leaks();
+}
+
+#[test]
+fn test_borrowing_accessors() {
+    let memgraph = Memgraph::new_default();
+    let string = CString::new("Memgraph").unwrap();
+    let values = vec![
+        Value::Null,
+        Value::Bool(true),
+        Value::Int(7),
+        Value::Float(0.5),
+        Value::String(string.clone()),
+        Value::Vertex(Vertex::new(null_mut(), &memgraph)),
+        Value::Edge(Edge::new(null_mut(), &memgraph)),
+        Value::Path(Path::new(null_mut(), &memgraph)),
+        Value::List(List::new(null_mut(), &memgraph)),
+        Value::Map(Map::new(null_mut(), &memgraph)),
+    ];
+
+    let matching = |value: &Value| -> Vec<&'static str> {
+        let mut names = vec![];
+        if value.as_bool().is_some() {
+            names.push("bool");
+        }
+        if value.as_int().is_some() {
+            names.push("int");
+        }
+        if value.as_float().is_some() {
+            names.push("float");
+        }
+        if value.as_str().is_some() {
+            names.push("str");
+        }
+        if value.as_vertex().is_some() {
+            names.push("vertex");
+        }
+        if value.as_edge().is_some() {
+            names.push("edge");
+        }
+        if value.as_path().is_some() {
+            names.push("path");
+        }
+        if value.as_list().is_some() {
+            names.push("list");
+        }
+        if value.as_map().is_some() {
+            names.push("map");
+        }
+        names
+    };
+    let expected: Vec<Vec<&str>> = vec![
+        vec![],
+        vec!["bool"],
+        vec!["int"],
+        vec!["float"],
+        vec!["str"],
+        vec!["vertex"],
+        vec!["edge"],
+        vec!["path"],
+        vec!["list"],
+        vec!["map"],
+    ];
+    assert_eq!(values.iter().map(matching).collect::<Vec<_>>(), expected);
+
+    assert_eq!(values[1].as_bool(), Some(true));
+    assert_eq!(values[2].as_int(), Some(7));
+    assert_eq!(values[3].as_float(), Some(0.5));
+    assert_eq!(values[4].as_str(), Some(string.as_c_str()));
+    assert!(values[8].as_list().unwrap().mgp_ptr().is_null());
+}
@@ -14,8 +14,10 @@
 //! All vertex (node) related.
 
 use std::ffi::{CStr, CString};
+use std::hash::{Hash, Hasher};
 
 use crate::edge::*;
+use crate::id::*;
 use crate::memgraph::*;
 use crate::mgp::*;
 use crate::property::*;
@@ -59,30 +61,30 @@ impl Drop for VerticesIterator {
 }
 
 impl Iterator for VerticesIterator {
-    type Item = Vertex;
+    type Item = Result<Vertex>;
 
-    fn next(&mut self) -> Option<Vertex> {
+    /// Yields an error if the next vertex can't be read or copied.
+    fn next(&mut self) -> Option<Result<Vertex>> {
         unsafe {
             let data = if self.is_first {
                 self.is_first = false;
-                invoke_mgp_func!(*mut mgp_vertex, ffi::mgp_vertices_iterator_get, self.ptr).unwrap()
+                invoke_mgp_func!(*mut mgp_vertex, ffi::mgp_vertices_iterator_get, self.ptr)
             } else {
                 invoke_mgp_func!(*mut mgp_vertex, ffi::mgp_vertices_iterator_next, self.ptr)
-                    .expect("Unable to get next vertex during vertices iteration.")
             };
 
-            if data.is_null() {
-                None
-            } else {
-                Some(match Vertex::mgp_copy(data, &self.memgraph) {
-                    Ok(v) => v,
-                    Err(_) => panic!("Unable to create new vertex during vertices iteration."),
-                })
+            match data {
+                Err(_) => Some(Err(Error::UnableToReturnNextGraphVertex)),
+                Ok(data) if data.is_null() => None,
+                Ok(data) => Some(Vertex::mgp_copy(data, &self.memgraph)),
             }
         }
     }
 }
 
+/// Vertices are compared and hashed by their [Id], which is read from Memgraph on each
+/// comparison. Two handles to the same vertex are equal, so e.g. a `HashSet<Vertex>` can be used
+/// as a visited set.
 pub struct Vertex {
     ptr: *mut mgp_vertex,
     memgraph: Memgraph,
@@ -98,6 +100,20 @@ impl Drop for Vertex {
     }
 }
 
+impl PartialEq for Vertex {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Vertex {}
+
+impl Hash for Vertex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
 impl Vertex {
     pub(crate) fn new(ptr: *mut mgp_vertex, memgraph: &Memgraph) -> Vertex {
         #[cfg(not(test))]
@@ -133,6 +149,16 @@ impl Vertex {
         Ok(Vertex::new(mgp_copy, &memgraph))
     }
 
+    /// Returns the underlying [mgp_vertex] pointer for interop with other C libraries that the safe
+    /// API doesn't cover. The ownership stays with the vertex.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is valid only while the vertex is alive and must not be destroyed by the caller.
+    pub unsafe fn as_ptr(&self) -> *mut mgp_vertex {
+        self.ptr
+    }
+
     pub(crate) fn mgp_ptr(&self) -> *mut mgp_vertex {
         self.ptr
     }
@@ -141,11 +167,24 @@ impl Vertex {
         self.ptr = new_ptr;
     }
 
-    pub fn id(&self) -> i64 {
+    /// Returns the [Memgraph] object the vertex belongs to.
+    pub(crate) fn memgraph(&self) -> &Memgraph {
+        &self.memgraph
+    }
+
+    /// Returns an owned copy of the vertex, e.g., to keep a vertex yielded by an iterator after
+    /// the iteration.
+    pub fn copy(&self) -> Result<Vertex> {
+        unsafe { Vertex::mgp_copy(self.ptr, &self.memgraph) }
+    }
+
+    pub fn id(&self) -> Id {
         unsafe {
-            invoke_mgp_func!(mgp_vertex_id, ffi::mgp_vertex_get_id, self.ptr)
-                .unwrap()
-                .as_int
+            Id(
+                invoke_mgp_func!(mgp_vertex_id, ffi::mgp_vertex_get_id, self.ptr)
+                    .unwrap()
+                    .as_int,
+            )
         }
     }
 
@@ -167,17 +206,26 @@ impl Vertex {
                     Err(MgpError::DeletedObject) => {
                         return Err(Error::UnableToReturnVertexLabelDeletedObjectError);
                     }
-                    Err(MgpError::OutOfRange) => return Err(Error::OutOfBoundLabelIndexError),
+                    Err(MgpError::OutOfRange) => return Err(self.out_of_bound_label(index)),
                     Err(_) => panic!("Unexpected error when getting vertex label"),
                     Ok(label) => label,
                 };
             if c_label.name.is_null() {
-                return Err(Error::OutOfBoundLabelIndexError);
+                return Err(self.out_of_bound_label(index));
             }
             create_cstring(c_label.name)
         }
     }
 
+    /// Reports the number of labels along with the invalid index, unless the count can't be
+    /// returned either.
+    fn out_of_bound_label(&self, index: u64) -> Error {
+        match self.labels_count() {
+            Ok(count) => Error::OutOfBoundLabelIndexError { index, count },
+            Err(error) => error,
+        }
+    }
+
     pub fn has_label(&self, name: &CStr) -> Result<bool> {
         unsafe {
             let c_mgp_label = mgp_label {
@@ -194,27 +242,83 @@ impl Vertex {
         }
     }
 
+    /// Sets the property to the given value, [Value::Null] removes the property.
+    ///
+    /// Fails early with [Error::ImmutableGraphError] if the procedure can't modify the graph.
+    pub fn set_property(&self, name: &CStr, value: &Value) -> Result<()> {
+        self.memgraph.check_mutable()?;
+        unsafe {
+            let mgp_value = value.to_mgp_value(&self.memgraph)?;
+            match invoke_void_mgp_func!(
+                ffi::mgp_vertex_set_property,
+                self.ptr,
+                name.as_ptr(),
+                mgp_value.mgp_ptr()
+            ) {
+                Ok(()) => Ok(()),
+                Err(MgpError::ImmutableObject) => Err(Error::ImmutableGraphError),
+                Err(_) => Err(Error::UnableToSetVertexProperty {
+                    name: name.to_string_lossy().into_owned(),
+                }),
+            }
+        }
+    }
+
+    /// Adds the label to the vertex, does nothing if the vertex already has it.
+    ///
+    /// Fails early with [Error::ImmutableGraphError] if the procedure can't modify the graph.
+    pub fn add_label(&self, name: &CStr) -> Result<()> {
+        self.memgraph.check_mutable()?;
+        unsafe {
+            let c_mgp_label = mgp_label {
+                name: name.as_ptr(),
+            };
+            match invoke_void_mgp_func!(ffi::mgp_vertex_add_label, self.ptr, c_mgp_label) {
+                Ok(()) => Ok(()),
+                Err(MgpError::ImmutableObject) => Err(Error::ImmutableGraphError),
+                Err(_) => Err(Error::UnableToAddVertexLabel),
+            }
+        }
+    }
+
+    /// All returned errors carry the property name.
     pub fn property(&self, name: &CStr) -> Result<Property> {
+        let name_string = || name.to_string_lossy().into_owned();
         unsafe {
             let mgp_value = invoke_mgp_func_with_res!(
                 *mut mgp_value,
-                Error::UnableToGetVertexProperty,
+                Error::UnableToGetVertexProperty {
+                    name: name_string(),
+                },
                 ffi::mgp_vertex_get_property,
                 self.ptr,
                 name.as_ptr(),
                 self.memgraph.memory_ptr()
             )?;
-            let value = MgpValue::new(mgp_value, &self.memgraph).to_value()?;
+            let value = match MgpValue::new(mgp_value, &self.memgraph).to_value() {
+                Ok(value) => value,
+                Err(_) => {
+                    return Err(Error::UnableToReturnVertexPropertyValueCreationError {
+                        name: name_string(),
+                    })
+                }
+            };
             match CString::new(name.to_bytes()) {
                 Ok(c_string) => Ok(Property {
                     name: c_string,
                     value,
                 }),
-                Err(_) => Err(Error::UnableToReturnVertexPropertyMakeNameEror),
+                Err(_) => Err(Error::UnableToReturnVertexPropertyMakeNameEror {
+                    name: name_string(),
+                }),
             }
         }
     }
 
+    /// Returns an iterator over all vertex properties, each one being a copy of the property name
+    /// and value.
+    ///
+    /// The underlying [mgp_properties_iterator] is destroyed once the returned iterator is dropped.
     pub fn properties(&self) -> Result<PropertiesIterator> {
         unsafe {
             let mgp_iterator = invoke_mgp_func_with_res!(
@@ -228,6 +332,26 @@ impl Vertex {
         }
     }
 
+    /// Returns the number of incoming edges, the edges are iterated (and copied) once.
+    pub fn in_degree(&self) -> Result<usize> {
+        let mut degree = 0;
+        for edge in self.in_edges()? {
+            edge?;
+            degree += 1;
+        }
+        Ok(degree)
+    }
+
+    /// Returns the number of outgoing edges, the edges are iterated (and copied) once.
+    pub fn out_degree(&self) -> Result<usize> {
+        let mut degree = 0;
+        for edge in self.out_edges()? {
+            edge?;
+            degree += 1;
+        }
+        Ok(degree)
+    }
+
     pub fn in_edges(&self) -> Result<EdgesIterator> {
         unsafe {
             let mgp_iterator = invoke_mgp_func_with_res!(
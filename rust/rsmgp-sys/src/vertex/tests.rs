@@ -13,14 +13,18 @@
 // limitations under the License.
 
 use c_str_macro::c_str;
+use chrono::NaiveDate;
 use serial_test::serial;
+use std::collections::HashSet;
 use std::ffi::{CStr, CString};
 use std::ptr::null_mut;
 
 use super::*;
 use crate::memgraph::Memgraph;
 use crate::mgp::mock_ffi::*;
+use crate::testing::alloc::*;
 use crate::{mock_mgp_once, with_dummy};
+use libc::{c_void, free};
 
 #[test]
 #[serial]
@@ -35,6 +39,61 @@ fn test_id() {
     });
 }
 
+#[test]
+#[serial]
+// The hash only depends on the vertex id, the shared records counter in Memgraph doesn't affect it.
+#[allow(clippy::mutable_key_type)]
+fn test_vertex_hash_set() {
+    // Both inserts hash the new vertex, the second one also compares it with the first.
+    let ctx_get_id = mgp_vertex_get_id_context();
+    ctx_get_id
+        .expect()
+        .times(4)
+        .returning(|_, vertex_id_ptr| unsafe {
+            (*vertex_id_ptr).as_int = 72;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+
+    with_dummy!(|memgraph: &Memgraph| {
+        let mut vertices = HashSet::new();
+        assert!(vertices.insert(Vertex::new(null_mut(), &memgraph)));
+        assert!(!vertices.insert(Vertex::new(null_mut(), &memgraph)));
+        assert_eq!(vertices.len(), 1);
+    });
+}
+
+#[test]
+#[serial]
+fn test_copy() {
+    mock_mgp_once!(mgp_vertex_copy_context, |_, _, vertex_ptr_ptr| unsafe {
+        (*vertex_ptr_ptr) = alloc_mgp_vertex();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_vertex_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let copy = vertex.copy();
+        assert!(copy.is_ok());
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_copy_unable_to_allocate() {
+    mock_mgp_once!(mgp_vertex_copy_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let copy = vertex.copy();
+        assert!(copy.is_err());
+        assert_eq!(copy.err().unwrap(), Error::UnableToCopyVertex);
+    });
+}
+
 #[test]
 #[serial]
 fn test_labels_count() {
@@ -84,6 +143,30 @@ fn test_label_at() {
     });
 }
 
+#[test]
+#[serial]
+fn test_label_at_out_of_bound() {
+    mock_mgp_once!(mgp_vertex_label_at_context, |_, _, _| {
+        mgp_error::MGP_ERROR_OUT_OF_RANGE
+    });
+    mock_mgp_once!(mgp_vertex_labels_count_context, |_, count_ptr| unsafe {
+        (*count_ptr) = 2;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let error = vertex.label_at(5).err().unwrap();
+        assert_eq!(
+            error,
+            Error::OutOfBoundLabelIndexError { index: 5, count: 2 }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Out of bound label index 5, the vertex has 2 labels."
+        );
+    });
+}
+
 #[test]
 #[serial]
 fn test_property() {
@@ -97,12 +180,53 @@ fn test_property() {
         }
     );
 
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let error = vertex.property(c_str!("test")).err().unwrap();
+        assert_eq!(
+            error,
+            Error::UnableToGetVertexProperty {
+                name: "test".to_string()
+            }
+        );
+        assert_eq!(error.to_string(), "Unable to get vertex property test.");
+    });
+}
+
+#[test]
+#[serial]
+fn test_property_value_creation_error() {
+    mock_mgp_once!(
+        mgp_vertex_get_property_context,
+        |_, _, _, value_ptr_ptr| unsafe {
+            (*value_ptr_ptr) = alloc_mgp_value();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+        (*type_ptr) = mgp_value_type::MGP_VALUE_TYPE_LIST;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_get_list_context, |_, _| {
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_size_context, |_, size_ptr| unsafe {
+        (*size_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_make_empty_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe { free_mgp(ptr) });
+
     with_dummy!(Vertex, |vertex: &Vertex| {
         assert_eq!(
             vertex.property(c_str!("test")).err().unwrap(),
-            Error::UnableToGetVertexProperty
+            Error::UnableToReturnVertexPropertyValueCreationError {
+                name: "test".to_string()
+            }
         );
     });
+    assert_no_leaks();
 }
 
 #[test]
@@ -143,3 +267,461 @@ fn test_out_edges() {
         assert!(iter.is_err());
     });
 }
+
+/// Mocks an edges iterator over the given number of edges, all allocations are tracked.
+macro_rules! mock_edges_iterator {
+    ($iter_context:ident, $degree:expr) => {
+        let degree: usize = $degree;
+        let position = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let position_next = position.clone();
+        mock_mgp_once!($iter_context, |_, _, iter_ptr_ptr| unsafe {
+            (*iter_ptr_ptr) = alloc_mgp_edges_iterator();
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+        mock_mgp_once!(
+            mgp_edges_iterator_get_context,
+            move |_, edge_ptr_ptr| unsafe {
+                position.store(1, std::sync::atomic::Ordering::SeqCst);
+                (*edge_ptr_ptr) = if degree > 0 {
+                    std::ptr::NonNull::dangling().as_ptr()
+                } else {
+                    null_mut()
+                };
+                mgp_error::MGP_ERROR_NO_ERROR
+            }
+        );
+        let ctx_next = mgp_edges_iterator_next_context();
+        ctx_next.expect().returning(move |_, edge_ptr_ptr| unsafe {
+            let next = position_next.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            (*edge_ptr_ptr) = if next < degree {
+                std::ptr::NonNull::dangling().as_ptr()
+            } else {
+                null_mut()
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+        let ctx_copy = mgp_edge_copy_context();
+        ctx_copy
+            .expect()
+            .times(degree)
+            .returning(|_, _, edge_ptr_ptr| unsafe {
+                (*edge_ptr_ptr) = alloc_mgp_edge();
+                mgp_error::MGP_ERROR_NO_ERROR
+            });
+        let ctx_destroy = mgp_edge_destroy_context();
+        ctx_destroy
+            .expect()
+            .times(degree)
+            .returning(|ptr| unsafe { free_mgp(ptr) });
+        mock_mgp_once!(mgp_edges_iterator_destroy_context, |ptr| unsafe {
+            free_mgp(ptr);
+        });
+    };
+}
+
+#[test]
+#[serial]
+fn test_in_degree() {
+    mock_edges_iterator!(mgp_vertex_iter_in_edges_context, 2);
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert_eq!(vertex.in_degree().unwrap(), 2);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_out_degree() {
+    mock_edges_iterator!(mgp_vertex_iter_out_edges_context, 3);
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert_eq!(vertex.out_degree().unwrap(), 3);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_out_degree_sink() {
+    mock_edges_iterator!(mgp_vertex_iter_out_edges_context, 0);
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert_eq!(vertex.out_degree().unwrap(), 0);
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_degree_iterator_error() {
+    mock_mgp_once!(mgp_vertex_iter_in_edges_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+    mock_mgp_once!(mgp_vertex_iter_out_edges_context, |_, _, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert_eq!(
+            vertex.in_degree().err().unwrap(),
+            Error::UnableToReturnVertexInEdgesIterator
+        );
+        assert_eq!(
+            vertex.out_degree().err().unwrap(),
+            Error::UnableToReturnVertexOutEdgesIterator
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_degree_edge_error_mid_stream() {
+    mock_mgp_once!(
+        mgp_vertex_iter_out_edges_context,
+        |_, _, iter_ptr_ptr| unsafe {
+            (*iter_ptr_ptr) = alloc_mgp_edges_iterator();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_edges_iterator_get_context, |_, edge_ptr_ptr| unsafe {
+        (*edge_ptr_ptr) = std::ptr::NonNull::dangling().as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_edge_copy_context, |_, _, edge_ptr_ptr| unsafe {
+        (*edge_ptr_ptr) = alloc_mgp_edge();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_edge_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+    mock_mgp_once!(mgp_edges_iterator_next_context, |_, _| {
+        mgp_error::MGP_ERROR_UNABLE_TO_ALLOCATE
+    });
+    mock_mgp_once!(mgp_edges_iterator_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert_eq!(
+            vertex.out_degree().err().unwrap(),
+            Error::UnableToReturnNextEdge
+        );
+    });
+    assert_no_leaks();
+}
+
+#[test]
+#[serial]
+fn test_properties_of_different_types() {
+    mock_mgp_once!(
+        mgp_vertex_iter_properties_context,
+        |_, _, iter_ptr_ptr| unsafe {
+            (*iter_ptr_ptr) = alloc_mgp_properties_iterator();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    let age_name = CString::new("age").unwrap();
+    mock_mgp_once!(
+        mgp_properties_iterator_get_context,
+        move |_, prop_ptr_ptr| unsafe {
+            (*prop_ptr_ptr) = Box::into_raw(Box::new(mgp_property {
+                name: age_name.as_ptr(),
+                value: alloc_mgp_value(),
+            }));
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    let name_name = CString::new("name").unwrap();
+    let mut next_calls = 0;
+    let ctx_next = mgp_properties_iterator_next_context();
+    ctx_next
+        .expect()
+        .times(2)
+        .returning(move |_, prop_ptr_ptr| unsafe {
+            next_calls += 1;
+            (*prop_ptr_ptr) = if next_calls == 1 {
+                Box::into_raw(Box::new(mgp_property {
+                    name: name_name.as_ptr(),
+                    value: alloc_mgp_value(),
+                }))
+            } else {
+                null_mut()
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    let mut get_type_calls = 0;
+    let ctx_get_type = mgp_value_get_type_context();
+    ctx_get_type
+        .expect()
+        .times(2)
+        .returning(move |_, type_ptr| unsafe {
+            get_type_calls += 1;
+            (*type_ptr) = if get_type_calls == 1 {
+                mgp_value_type::MGP_VALUE_TYPE_INT
+            } else {
+                mgp_value_type::MGP_VALUE_TYPE_STRING
+            };
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+    mock_mgp_once!(mgp_value_get_int_context, |_, int_ptr| unsafe {
+        (*int_ptr) = 42;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let name_value = CString::new("Alice").unwrap();
+    mock_mgp_once!(
+        mgp_value_get_string_context,
+        move |_, string_ptr_ptr| unsafe {
+            (*string_ptr_ptr) = name_value.as_ptr();
+            mgp_error::MGP_ERROR_NO_ERROR
+        }
+    );
+    mock_mgp_once!(mgp_properties_iterator_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let properties: Vec<Property> =
+            vertex.properties().unwrap().collect::<Result<_>>().unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0].name, CString::new("age").unwrap());
+        assert!(matches!(properties[0].value, Value::Int(42)));
+        assert_eq!(properties[1].name, CString::new("name").unwrap());
+        match &properties[1].value {
+            Value::String(value) => assert_eq!(value, &CString::new("Alice").unwrap()),
+            _ => panic!("Expected a string property value."),
+        }
+    });
+}
+
+macro_rules! mock_property_of_type {
+    ($value_type:expr) => {
+        mock_mgp_once!(
+            mgp_vertex_get_property_context,
+            |_, _, _, value_ptr_ptr| unsafe {
+                (*value_ptr_ptr) = alloc_mgp_value();
+                mgp_error::MGP_ERROR_NO_ERROR
+            }
+        );
+        mock_mgp_once!(mgp_value_get_type_context, |_, type_ptr| unsafe {
+            (*type_ptr) = $value_type;
+            mgp_error::MGP_ERROR_NO_ERROR
+        });
+        mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+            free(ptr as *mut c_void);
+        });
+    };
+}
+
+#[test]
+#[serial]
+fn test_int_property() {
+    mock_property_of_type!(mgp_value_type::MGP_VALUE_TYPE_INT);
+    mock_mgp_once!(mgp_value_get_int_context, |_, int_ptr| unsafe {
+        (*int_ptr) = 3;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let property = vertex.property(c_str!("prop")).unwrap();
+        assert_eq!(property.name.as_c_str(), c_str!("prop"));
+        assert_eq!(property.value, Value::Int(3));
+    });
+}
+
+#[test]
+#[serial]
+fn test_double_property() {
+    mock_property_of_type!(mgp_value_type::MGP_VALUE_TYPE_DOUBLE);
+    mock_mgp_once!(mgp_value_get_double_context, |_, double_ptr| unsafe {
+        (*double_ptr) = 0.5;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let property = vertex.property(c_str!("prop")).unwrap();
+        assert_eq!(property.value, Value::Float(0.5));
+    });
+}
+
+#[test]
+#[serial]
+fn test_string_property() {
+    mock_property_of_type!(mgp_value_type::MGP_VALUE_TYPE_STRING);
+    mock_mgp_once!(mgp_value_get_string_context, |_, string_ptr| unsafe {
+        (*string_ptr) = c_str!("text").as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let property = vertex.property(c_str!("prop")).unwrap();
+        assert_eq!(property.value, Value::String(CString::new("text").unwrap()));
+    });
+}
+
+#[test]
+#[serial]
+fn test_bool_property() {
+    mock_property_of_type!(mgp_value_type::MGP_VALUE_TYPE_BOOL);
+    mock_mgp_once!(mgp_value_get_bool_context, |_, bool_ptr| unsafe {
+        (*bool_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let property = vertex.property(c_str!("prop")).unwrap();
+        assert_eq!(property.value, Value::Bool(true));
+    });
+}
+
+#[test]
+#[serial]
+fn test_list_property() {
+    mock_property_of_type!(mgp_value_type::MGP_VALUE_TYPE_LIST);
+    mock_mgp_once!(mgp_value_get_list_context, |_, list_ptr_ptr| unsafe {
+        (*list_ptr_ptr) = null_mut();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_size = mgp_list_size_context();
+    ctx_size.expect().returning(|_, size_ptr| unsafe {
+        (*size_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_make_empty_context, |_, _, list_ptr_ptr| unsafe {
+        (*list_ptr_ptr) = alloc_mgp_list();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_list_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        match vertex.property(c_str!("prop")).unwrap().value {
+            Value::List(list) => assert_eq!(list.size(), 0),
+            _ => panic!("Expected a list property."),
+        }
+    });
+}
+
+#[test]
+#[serial]
+fn test_date_property() {
+    mock_property_of_type!(mgp_value_type::MGP_VALUE_TYPE_DATE);
+    mock_mgp_once!(mgp_value_get_date_context, |_, date_ptr_ptr| unsafe {
+        (*date_ptr_ptr) = std::ptr::NonNull::dangling().as_ptr();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_date_get_year_context, |_, year_ptr| unsafe {
+        (*year_ptr) = 2021;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_date_get_month_context, |_, month_ptr| unsafe {
+        (*month_ptr) = 6;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_date_get_day_context, |_, day_ptr| unsafe {
+        (*day_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    // The date is owned by the property value and must not be destroyed on its own.
+    let ctx_date_destroy = mgp_date_destroy_context();
+    ctx_date_destroy.expect().times(0);
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        let property = vertex.property(c_str!("prop")).unwrap();
+        assert_eq!(property.value, Value::Date(NaiveDate::from_ymd(2021, 6, 1)));
+    });
+}
+
+#[test]
+#[serial]
+fn test_set_property_in_read_procedure() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_set_property = mgp_vertex_set_property_context();
+    ctx_set_property.expect().times(0);
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert_eq!(
+            vertex.set_property(c_str!("rank"), &Value::Int(1)),
+            Err(Error::ImmutableGraphError)
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_add_label_in_read_procedure() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 0;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    let ctx_add_label = mgp_vertex_add_label_context();
+    ctx_add_label.expect().times(0);
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert_eq!(
+            vertex.add_label(c_str!("Ranked")),
+            Err(Error::ImmutableGraphError)
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_set_property() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_make_int_context, |_, _, value_ptr_ptr| unsafe {
+        (*value_ptr_ptr) = alloc_mgp_value();
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_vertex_set_property_context, |_, name, _| unsafe {
+        assert_eq!(CStr::from_ptr(name), c_str!("rank"));
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_value_destroy_context, |ptr| unsafe {
+        free(ptr as *mut c_void);
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert!(vertex.set_property(c_str!("rank"), &Value::Int(1)).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_add_label() {
+    mock_mgp_once!(mgp_graph_is_mutable_context, |_, result_ptr| unsafe {
+        (*result_ptr) = 1;
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+    mock_mgp_once!(mgp_vertex_add_label_context, |_, label| unsafe {
+        assert_eq!(CStr::from_ptr(label.name), c_str!("Ranked"));
+        mgp_error::MGP_ERROR_NO_ERROR
+    });
+
+    with_dummy!(Vertex, |vertex: &Vertex| {
+        assert!(vertex.add_label(c_str!("Ranked")).is_ok());
+    });
+}
+
+#[test]
+#[serial]
+fn test_as_ptr() {
+    mock_mgp_once!(mgp_vertex_destroy_context, |ptr| unsafe {
+        free_mgp(ptr);
+    });
+
+    let memgraph = Memgraph::new_default();
+    unsafe {
+        let ptr = alloc_mgp_vertex();
+        let vertex = Vertex::new(ptr, &memgraph);
+        assert_eq!(vertex.as_ptr(), ptr);
+    }
+    assert_no_leaks();
+}